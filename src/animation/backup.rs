@@ -9,7 +9,14 @@ use crate::animation::SaveBackupSystems;
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.configure::<(Backup<Transform>, Backup<BoxShadow>)>();
+    app.configure::<(
+        Backup<Transform>,
+        Backup<BoxShadow>,
+        Backup<Sprite>,
+        Backup<ImageNode>,
+        Backup<BackgroundColor>,
+        Backup<BorderColor>,
+    )>();
 
     // Restore `GlobalTransform` after restoring `Transform`.
     app.add_systems(
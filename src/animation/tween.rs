@@ -0,0 +1,329 @@
+use bevy::color::ColorToComponents;
+use bevy::ecs::component::Mutable;
+use bevy::math::curve::Curve;
+use bevy::math::curve::EaseFunction;
+use bevy::math::curve::EasingCurve;
+use bevy::reflect::Reflectable;
+
+use crate::animation::PostColorSystems;
+use crate::animation::PostTransformSystems;
+use crate::animation::backup::Backup;
+use crate::prelude::*;
+use crate::theme::color::ColorMut;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        Tween<Transform>,
+        Tween<Sprite>,
+        Tween<ImageNode>,
+        Tween<BackgroundColor>,
+        Tween<BorderColor>,
+    )>();
+}
+
+/// A component that a [`Tween`] can blend an animated value into.
+pub trait Tweenable: Component<Mutability = Mutable> + Clone + Default + TypePath {
+    type Value: Reflectable + FromReflect + Clone;
+
+    /// The post-processing step to blend in.
+    fn blend_systems() -> impl SystemSet;
+
+    fn lerp(from: &Self::Value, to: &Self::Value, t: f32) -> Self::Value;
+
+    fn blend(&mut self, value: &Self::Value);
+}
+
+impl Tweenable for Transform {
+    type Value = Transform;
+
+    fn blend_systems() -> impl SystemSet {
+        PostTransformSystems::Blend
+    }
+
+    fn lerp(from: &Self::Value, to: &Self::Value, t: f32) -> Self::Value {
+        Transform {
+            translation: from.translation.lerp(to.translation, t),
+            rotation: from.rotation.slerp(to.rotation, t),
+            scale: from.scale.lerp(to.scale, t),
+        }
+    }
+
+    fn blend(&mut self, value: &Self::Value) {
+        self.translation += value.translation;
+        self.rotation = value.rotation * self.rotation;
+        self.scale *= value.scale;
+    }
+}
+
+impl<C: ColorMut + Clone + Default + TypePath> Tweenable for C {
+    type Value = LinearRgba;
+
+    fn blend_systems() -> impl SystemSet {
+        PostColorSystems::Blend
+    }
+
+    fn lerp(from: &Self::Value, to: &Self::Value, t: f32) -> Self::Value {
+        LinearRgba::from_vec4(from.to_vec4().lerp(to.to_vec4(), t))
+    }
+
+    fn blend(&mut self, value: &Self::Value) {
+        let color = self.color_mut();
+        *color = LinearRgba::from_vec4(color.to_linear().to_vec4() * value.to_vec4()).into();
+    }
+}
+
+/// Animate a value over time and blend it into a [`Tweenable`] component.
+///
+/// The value is blended on top of the component's [`Backup`], so tweens compose with each
+/// other and with other animations instead of overwriting the component.
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
+#[require(Backup<C>)]
+pub struct Tween<C: Tweenable> {
+    pub from: C::Value,
+    pub to: C::Value,
+    /// The duration of one play, in seconds.
+    pub duration: f32,
+    pub ease: EaseFunction,
+    /// The time to wait before the first play, in seconds.
+    pub delay: f32,
+    pub repeat: TweenRepeat,
+    /// Whether to play backwards on every other repeat.
+    pub ping_pong: bool,
+    elapsed: f32,
+}
+
+impl<C: Tweenable> Configure for Tween<C> {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(Update, tick_tweens::<C>.in_set(UpdateSystems::TickTimers));
+        app.add_systems(PostUpdate, apply_tweens::<C>.in_set(C::blend_systems()));
+    }
+}
+
+impl<C: Tweenable> Tween<C> {
+    #[allow(dead_code)]
+    pub fn new(from: C::Value, to: C::Value) -> Self {
+        Self {
+            from,
+            to,
+            duration: 1.0,
+            ease: EaseFunction::Linear,
+            delay: 0.0,
+            repeat: TweenRepeat::Times(0),
+            ping_pong: false,
+            elapsed: 0.0,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn over(mut self, secs: f32) -> Self {
+        self.duration = secs;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_delay(mut self, secs: f32) -> Self {
+        self.delay = secs;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn repeat(mut self, times: u32) -> Self {
+        self.repeat = TweenRepeat::Times(times);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn repeat_forever(mut self) -> Self {
+        self.repeat = TweenRepeat::Forever;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn ping_pong(mut self) -> Self {
+        self.ping_pong = true;
+        self
+    }
+
+    /// Restart from the beginning, including the delay.
+    #[allow(dead_code)]
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Whether the last repeat has finished.
+    ///
+    /// A tween with a non-positive duration finishes right after its delay, even if it
+    /// repeats forever.
+    pub fn is_finished(&self) -> bool {
+        if self.duration <= 0.0 {
+            return self.elapsed > self.delay;
+        }
+        match self.repeat {
+            TweenRepeat::Times(n) => self.elapsed - self.delay >= (n + 1) as f32 * self.duration,
+            TweenRepeat::Forever => false,
+        }
+    }
+
+    /// The eased progress from `from` (0) to `to` (1).
+    pub fn progress(&self) -> f32 {
+        let (play, t) = if self.is_finished() {
+            let last_play = match self.repeat {
+                TweenRepeat::Times(n) => n,
+                TweenRepeat::Forever => 0,
+            };
+            (last_play, 1.0)
+        } else if self.duration > 0.0 {
+            let plays = ((self.elapsed - self.delay) / self.duration).max(0.0);
+            (plays as u32, plays.fract())
+        } else {
+            // Still in the delay.
+            (0, 0.0)
+        };
+        let t = if self.ping_pong && play % 2 == 1 {
+            1.0 - t
+        } else {
+            t
+        };
+
+        EasingCurve::new(0.0, 1.0, self.ease).sample_clamped(t)
+    }
+
+    /// The current animated value.
+    pub fn value(&self) -> C::Value {
+        C::lerp(&self.from, &self.to, self.progress())
+    }
+}
+
+#[derive(Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TweenRepeat {
+    /// Repeat a number of times after the first play.
+    Times(u32),
+    Forever,
+}
+
+/// Triggered on an entity when one of its [`Tween`]s finishes its last repeat.
+///
+/// The tween keeps blending its final value until it's removed.
+#[derive(Event, Reflect, Copy, Clone, Debug)]
+pub struct TweenFinished;
+
+fn tick_tweens<C: Tweenable>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tween_query: Query<(Entity, &mut Tween<C>)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut tween) in &mut tween_query {
+        if tween.is_finished() {
+            continue;
+        }
+
+        tween.elapsed += dt;
+        if tween.is_finished() {
+            commands.trigger_targets(TweenFinished, entity);
+        }
+    }
+}
+
+fn apply_tweens<C: Tweenable>(mut tween_query: Query<(&Tween<C>, &mut C)>) {
+    for (tween, mut target) in &mut tween_query {
+        target.blend(&tween.value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        for (repeat, ping_pong, elapsed, want) in [
+            // Delay.
+            (TweenRepeat::Times(0), false, 0.5, 0.0),
+            (TweenRepeat::Times(0), false, 1.5, 0.5),
+            (TweenRepeat::Times(0), false, 3.5, 1.0),
+            // Repeat.
+            (TweenRepeat::Times(1), false, 2.5, 0.5),
+            (TweenRepeat::Times(1), false, 3.5, 1.0),
+            (TweenRepeat::Forever, false, 10.25, 0.25),
+            // Ping-pong.
+            (TweenRepeat::Times(1), true, 2.25, 0.75),
+            (TweenRepeat::Times(1), true, 3.5, 0.0),
+            (TweenRepeat::Times(2), true, 4.5, 1.0),
+            (TweenRepeat::Forever, true, 2.25, 0.75),
+        ] {
+            let mut tween = Tween::<Transform>::new(default(), default()).with_delay(1.0);
+            tween.repeat = repeat;
+            tween.ping_pong = ping_pong;
+            tween.elapsed = elapsed;
+            assert_eq!(
+                tween.progress(),
+                want,
+                "{repeat:?}, ping-pong: {ping_pong}, elapsed: {elapsed}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_positive_duration() {
+        for (repeat, duration, elapsed, want) in [
+            (TweenRepeat::Times(0), 0.0, 0.5, (false, 0.0)),
+            (TweenRepeat::Times(0), 0.0, 1.5, (true, 1.0)),
+            (TweenRepeat::Times(2), -1.0, 0.5, (false, 0.0)),
+            (TweenRepeat::Times(2), -1.0, 1.5, (true, 1.0)),
+            (TweenRepeat::Forever, 0.0, 0.5, (false, 0.0)),
+            (TweenRepeat::Forever, 0.0, 1.5, (true, 1.0)),
+        ] {
+            let mut tween = Tween::<Transform>::new(default(), default())
+                .over(duration)
+                .with_delay(1.0);
+            tween.repeat = repeat;
+            tween.elapsed = elapsed;
+            assert_eq!(
+                (tween.is_finished(), tween.progress()),
+                want,
+                "{repeat:?}, duration: {duration}, elapsed: {elapsed}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_finished_event() {
+        #[derive(Resource, Default)]
+        struct FinishedCount(usize);
+
+        for duration in [1.0, 0.0] {
+            let mut app = App::new();
+            app.init_resource::<Time>();
+            app.init_resource::<FinishedCount>();
+            app.add_systems(Update, tick_tweens::<Transform>);
+            app.add_observer(
+                |_: Trigger<TweenFinished>, mut count: ResMut<FinishedCount>| {
+                    count.0 += 1;
+                },
+            );
+            app.world_mut()
+                .spawn(Tween::<Transform>::new(default(), default()).over(duration));
+
+            for _ in 0..8 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_secs_f32(0.25));
+                app.update();
+            }
+            assert_eq!(
+                app.world().resource::<FinishedCount>().0,
+                1,
+                "duration: {duration}",
+            );
+        }
+    }
+}
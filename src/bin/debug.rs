@@ -3,11 +3,22 @@
 // Silence dead code warnings while writing debugging code.
 #![allow(dead_code)]
 
+use std::fmt::Write as _;
+
 use bevy::app::MainScheduleOrder;
+use bevy::asset::io::file::FileAssetReader;
 use bevy::ecs::schedule::LogLevel;
 use bevy::ecs::schedule::ScheduleLabel;
 use bevy::prelude::*;
+use bevy::reflect::NamedField;
+use bevy::reflect::TypeInfo;
+use bevy::reflect::UnnamedField;
+use bevy::reflect::VariantInfo;
 use bevy_mod_debugdump::schedule_graph_dot;
+use pyri_new_jam::ConfigFormat;
+use pyri_new_jam::ReflectConfig;
+use serde_json::Value;
+use serde_json::json;
 use tiny_bail::prelude::*;
 
 fn main() {
@@ -19,6 +30,7 @@ fn main() {
     list_schedules(app);
     list_main_schedules(app);
     list_systems(app, Update);
+    //export_configs(app);
 }
 
 /// Usage: Disable logging with RUST_LOG=off, then pipe the output into `dot`.
@@ -87,3 +99,184 @@ fn list_systems(app: &mut App, label: impl ScheduleLabel + Clone) {
         println!("[{label:?}] {system_name}");
     }
 }
+
+/// Write a default config file (documented if RON) and a JSON Schema for each config type.
+/// Example: `.dev.ron` -> `.dev.ron.example` and `.dev.schema.json`.
+fn export_configs(app: &mut App) {
+    let root = FileAssetReader::get_base_path().join("assets");
+    let registry = r!(app.world().get_resource::<AppTypeRegistry>()).read();
+
+    for (registration, config) in registry.iter_with_data::<ReflectConfig>() {
+        let info = registration.type_info();
+        let folder = root.join(config.folder);
+        let stem = config.file.rsplit_once('.').map_or(config.file, |(x, _)| x);
+
+        // Fall back to the current base file if there is no default value.
+        let default = registration.data::<ReflectDefault>();
+        let value = if let Some(default) = default {
+            default.default()
+        } else {
+            let text = c!(std::fs::read_to_string(folder.join(config.file)));
+            c!((config.deserialize)(&text))
+        };
+        let mut text = c!((config.serialize)(value.as_ref()));
+        if config.format == ConfigFormat::Ron {
+            text = document_ron(info, &text);
+        }
+        c!(std::fs::write(
+            folder.join(format!("{}.example", config.file)),
+            text,
+        ));
+
+        // Assume that a config with a default value has `#[serde(default)]`.
+        let mut schema = type_schema(info, default.is_none());
+        schema["$schema"] = json!("https://json-schema.org/draft/2020-12/schema");
+        schema["title"] = json!(info.type_path_table().short_path());
+        c!(std::fs::write(
+            folder.join(format!("{stem}.schema.json")),
+            c!(serde_json::to_string_pretty(&schema)),
+        ));
+
+        println!("Exported config: {}", info.type_path());
+    }
+}
+
+/// Insert the type's documentation as comments above its top-level fields.
+fn document_ron(info: &TypeInfo, ron: &str) -> String {
+    let mut documented = String::new();
+    for line in info.docs().unwrap_or_default().lines() {
+        let _ = writeln!(documented, "//{line}");
+    }
+
+    for line in ron.lines() {
+        let field = line
+            .strip_prefix("    ")
+            .filter(|x| !x.starts_with(' '))
+            .and_then(|x| x.split_once(':'))
+            .and_then(|(name, _)| match info {
+                TypeInfo::Struct(info) => info.field(name),
+                _ => None,
+            });
+        for doc in field.and_then(NamedField::docs).unwrap_or_default().lines() {
+            let _ = writeln!(documented, "    //{doc}");
+        }
+        let _ = writeln!(documented, "{line}");
+    }
+
+    documented
+}
+
+/// Convert reflected type info into a JSON Schema for its serde representation.
+fn type_schema(info: &TypeInfo, is_required: bool) -> Value {
+    let mut schema = match info {
+        TypeInfo::Struct(info) => fields_schema(info.iter(), is_required),
+        TypeInfo::TupleStruct(info) if info.field_len() == 1 => {
+            maybe_schema(info.field_at(0).and_then(UnnamedField::type_info))
+        },
+        TypeInfo::TupleStruct(info) => {
+            tuple_schema(info.iter().map(|x| maybe_schema(x.type_info())))
+        },
+        TypeInfo::Tuple(info) => tuple_schema(info.iter().map(|x| maybe_schema(x.type_info()))),
+        TypeInfo::List(info) => json!({
+            "type": "array",
+            "items": maybe_schema(info.item_info()),
+        }),
+        TypeInfo::Array(info) => json!({
+            "type": "array",
+            "items": maybe_schema(info.item_info()),
+            "minItems": info.capacity(),
+            "maxItems": info.capacity(),
+        }),
+        TypeInfo::Set(_) => json!({ "type": "array", "uniqueItems": true }),
+        TypeInfo::Map(info) => json!({
+            "type": "object",
+            "additionalProperties": maybe_schema(info.value_info()),
+        }),
+        TypeInfo::Enum(info) if info.type_path().starts_with("core::option::Option<") => {
+            let some = match info.variant("Some") {
+                Some(VariantInfo::Tuple(x)) => {
+                    maybe_schema(x.field_at(0).and_then(UnnamedField::type_info))
+                },
+                _ => json!({}),
+            };
+            json!({ "anyOf": [some, { "type": "null" }] })
+        },
+        TypeInfo::Enum(info) => {
+            let variants = info.iter().map(|variant| {
+                let mut schema = match variant {
+                    VariantInfo::Unit(_) => return json!({ "const": variant.name() }),
+                    VariantInfo::Tuple(x) if x.field_len() == 1 => {
+                        maybe_schema(x.field_at(0).and_then(UnnamedField::type_info))
+                    },
+                    VariantInfo::Tuple(x) => {
+                        tuple_schema(x.iter().map(|x| maybe_schema(x.type_info())))
+                    },
+                    VariantInfo::Struct(x) => fields_schema(x.iter(), true),
+                };
+                if let Some(docs) = variant.docs() {
+                    schema["description"] = json!(docs.trim());
+                }
+                json!({
+                    "type": "object",
+                    "properties": { variant.name(): schema },
+                    "required": [variant.name()],
+                    "additionalProperties": false,
+                })
+            });
+            json!({ "oneOf": variants.collect::<Vec<_>>() })
+        },
+        TypeInfo::Opaque(info) => match info.type_path() {
+            "bool" => json!({ "type": "boolean" }),
+            "f32" | "f64" => json!({ "type": "number" }),
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+                json!({ "type": "integer", "minimum": 0 })
+            },
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => json!({ "type": "integer" }),
+            "char" | "alloc::string::String" | "alloc::borrow::Cow<str>" => {
+                json!({ "type": "string" })
+            },
+            _ => json!({}),
+        },
+    };
+
+    if let Some(docs) = info.docs() {
+        schema["description"] = json!(docs.trim());
+    }
+    schema
+}
+
+fn maybe_schema(info: Option<&TypeInfo>) -> Value {
+    info.map_or(json!({}), |x| type_schema(x, true))
+}
+
+fn fields_schema<'a>(fields: impl Iterator<Item = &'a NamedField>, is_required: bool) -> Value {
+    let mut properties = serde_json::Map::new();
+    let mut required = vec![];
+    for field in fields {
+        let mut schema = maybe_schema(field.type_info());
+        if let Some(docs) = field.docs() {
+            schema["description"] = json!(docs.trim());
+        }
+        properties.insert(field.name().to_string(), schema);
+        if is_required {
+            required.push(field.name());
+        }
+    }
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn tuple_schema(items: impl Iterator<Item = Value>) -> Value {
+    let items = items.collect::<Vec<_>>();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "minItems": items.len(),
+        "items": false,
+    })
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<AccessibilitySettings>();
+}
+
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+#[reflect(Resource)]
+pub struct AccessibilitySettings {
+    /// Whether to reduce or disable motion effects like camera shake.
+    pub reduced_motion: bool,
+}
+
+impl Configure for AccessibilitySettings {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
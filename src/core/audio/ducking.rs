@@ -0,0 +1,177 @@
+use bevy::window::PrimaryWindow;
+
+use crate::core::audio::AudioCategories;
+use crate::core::audio::AudioCategory;
+use crate::core::audio::AudioSettings;
+use crate::menu::Menu;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<AudioDucking>();
+}
+
+/// Rules for temporarily lowering the volume of an [`AudioCategory`].
+///
+/// The resulting gain is multiplied into the volume from `AudioSettings`, and applies to
+/// subcategories of the target category as well.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct AudioDucking {
+    pub rules: Vec<DuckRule>,
+}
+
+impl Configure for AudioDucking {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            (
+                sync_unfocused_ducking.run_if(resource_changed::<AudioSettings>),
+                update_ducking,
+            )
+                .chain()
+                .in_set(UpdateSystems::Update),
+        );
+    }
+}
+
+impl Default for AudioDucking {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                DuckRule::new(DuckTrigger::Menu(Menu::Pause), AudioCategory::MUSIC, 9.0),
+                DuckRule::new(DuckTrigger::Menu(Menu::Settings), AudioCategory::MUSIC, 9.0),
+                // The decibels are set from `AudioSettings::unfocused_decibels`.
+                DuckRule::new(DuckTrigger::Unfocused, AudioCategory::MASTER, 0.0)
+                    .with_attack(0.5)
+                    .with_release(0.25),
+            ],
+        }
+    }
+}
+
+impl AudioDucking {
+    /// The current linear gain of an [`AudioCategory`] from ducking, excluding its ancestors.
+    pub fn gain(&self, category: &AudioCategory) -> f32 {
+        self.rules
+            .iter()
+            // Skip inactive rules, which could otherwise compute `inf * 0` decibels.
+            .filter(|x| x.target == *category && x.amount > 0.0)
+            .map(|x| Volume::Decibels(-x.decibels * x.amount).to_linear())
+            .product()
+    }
+}
+
+/// A rule that lowers the volume of a target [`AudioCategory`] while its trigger is active.
+#[derive(Reflect, Clone, Debug)]
+pub struct DuckRule {
+    pub trigger: DuckTrigger,
+    pub target: AudioCategory,
+    /// How much to lower the volume by, in decibels.
+    pub decibels: f32,
+    /// The time to fully duck after the trigger activates, in seconds.
+    pub attack_secs: f32,
+    /// The time to fully recover after the trigger deactivates, in seconds.
+    pub release_secs: f32,
+    /// The current progress from 0 (not ducked) to 1 (fully ducked).
+    amount: f32,
+}
+
+impl DuckRule {
+    pub fn new(trigger: DuckTrigger, target: AudioCategory, decibels: f32) -> Self {
+        Self {
+            trigger,
+            target,
+            decibels,
+            attack_secs: 0.15,
+            release_secs: 0.5,
+            amount: 0.0,
+        }
+    }
+
+    pub fn with_attack(mut self, secs: f32) -> Self {
+        self.attack_secs = secs;
+        self
+    }
+
+    pub fn with_release(mut self, secs: f32) -> Self {
+        self.release_secs = secs;
+        self
+    }
+}
+
+#[derive(Reflect, Clone, Eq, PartialEq, Debug)]
+pub enum DuckTrigger {
+    /// The menu is the current menu.
+    Menu(Menu),
+    /// Any sound in the category or its subcategories is playing.
+    Category(AudioCategory),
+    /// The primary window is unfocused.
+    Unfocused,
+}
+
+fn sync_unfocused_ducking(audio_settings: Res<AudioSettings>, mut ducking: ResMut<AudioDucking>) {
+    for rule in &mut ducking.rules {
+        if rule.trigger == DuckTrigger::Unfocused {
+            rule.decibels = audio_settings.unfocused_decibels;
+        }
+    }
+}
+
+fn update_ducking(
+    time: Res<Time>,
+    menu: CurrentRef<Menu>,
+    window: Option<Single<&Window, With<PrimaryWindow>>>,
+    categories: Res<AudioCategories>,
+    sink_query: Query<(&AudioCategory, &AudioSink)>,
+    spatial_sink_query: Query<(&AudioCategory, &SpatialAudioSink)>,
+    mut ducking: ResMut<AudioDucking>,
+) {
+    let dt = time.delta_secs();
+    let mut changed = false;
+    for rule in &mut ducking.bypass_change_detection().rules {
+        let is_active = match &rule.trigger {
+            DuckTrigger::Menu(x) => menu.is_in(x),
+            DuckTrigger::Category(x) => {
+                sink_query
+                    .iter()
+                    .any(|(c, sink)| is_playing(sink) && categories.is_within(c, x))
+                    || spatial_sink_query
+                        .iter()
+                        .any(|(c, sink)| is_playing(sink) && categories.is_within(c, x))
+            },
+            DuckTrigger::Unfocused => window.as_ref().is_some_and(|x| !x.focused),
+        };
+
+        let amount = if is_active {
+            step_towards(rule.amount, 1.0, dt, rule.attack_secs)
+        } else {
+            step_towards(rule.amount, 0.0, dt, rule.release_secs)
+        };
+        changed |= amount != rule.amount;
+        rule.amount = amount;
+    }
+
+    // Only update the `CategoryVolumes` when the gain actually changes.
+    if changed {
+        ducking.set_changed();
+    }
+}
+
+fn is_playing(sink: &impl AudioSinkPlayback) -> bool {
+    !sink.is_paused() && !sink.empty()
+}
+
+fn step_towards(from: f32, to: f32, dt: f32, duration: f32) -> f32 {
+    if duration <= 0.0 {
+        return to;
+    }
+
+    let step = dt / duration;
+    if from < to {
+        (from + step).min(to)
+    } else {
+        (from - step).max(to)
+    }
+}
@@ -0,0 +1,389 @@
+pub mod ducking;
+pub mod music;
+pub mod sound;
+pub mod spatial;
+pub mod synth;
+pub mod voice;
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use bevy::audio::AudioPlaySet;
+use bevy::audio::AudioPlugin;
+
+use crate::core::audio::ducking::AudioDucking;
+use crate::core::audio::music::DirectedMusic;
+use crate::core::audio::sound::Sound;
+use crate::core::audio::spatial::SpatialSfx;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        AudioCategory,
+        AudioCategories,
+        AudioSettings,
+        CategoryVolumes,
+    )>();
+
+    app.add_plugins((
+        AudioPlugin::default(),
+        ducking::plugin,
+        music::plugin,
+        sound::plugin,
+        spatial::plugin,
+        synth::plugin,
+        voice::plugin,
+    ));
+}
+
+/// A category of sounds that share a volume setting, like `"sfx"` or `"footsteps"`.
+#[derive(Component, Reflect, Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[reflect(Component)]
+#[serde(transparent)]
+pub struct AudioCategory(pub Cow<'static, str>);
+
+impl Configure for AudioCategory {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+impl AudioCategory {
+    pub const MASTER: Self = Self(Cow::Borrowed("master"));
+    pub const MUSIC: Self = Self(Cow::Borrowed("music"));
+    pub const SFX: Self = Self(Cow::Borrowed("sfx"));
+    pub const UI: Self = Self(Cow::Borrowed("ui"));
+
+    #[allow(dead_code)]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// The registered [`AudioCategory`] hierarchy, in settings menu order.
+///
+/// A category's volume is scaled by the volumes of all of its ancestors.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct AudioCategories(pub Vec<AudioCategoryInfo>);
+
+impl Configure for AudioCategories {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
+
+impl Default for AudioCategories {
+    fn default() -> Self {
+        Self(vec![
+            AudioCategoryInfo::new(AudioCategory::MASTER, None, "Master"),
+            AudioCategoryInfo::new(AudioCategory::MUSIC, Some(AudioCategory::MASTER), "Music"),
+            AudioCategoryInfo::new(AudioCategory::SFX, Some(AudioCategory::MASTER), "SFX"),
+            AudioCategoryInfo::new(AudioCategory::UI, Some(AudioCategory::MASTER), "UI"),
+        ])
+    }
+}
+
+impl AudioCategories {
+    /// Register a new category, e.g. `"footsteps"` under [`AudioCategory::SFX`].
+    #[allow(dead_code)]
+    pub fn add(
+        &mut self,
+        category: AudioCategory,
+        parent: AudioCategory,
+        label: impl Into<String>,
+    ) -> &mut Self {
+        self.0
+            .push(AudioCategoryInfo::new(category, Some(parent), label));
+        self
+    }
+
+    pub fn get(&self, category: &AudioCategory) -> Option<&AudioCategoryInfo> {
+        self.0.iter().find(|x| x.category == *category)
+    }
+
+    /// Iterate over a category followed by its ancestors.
+    pub fn ancestors<'a>(
+        &'a self,
+        category: &'a AudioCategory,
+    ) -> impl Iterator<Item = &'a AudioCategory> {
+        std::iter::successors(Some(category), |x| {
+            self.get(x).and_then(|x| x.parent.as_ref())
+        })
+        // Guard against cycles.
+        .take(self.0.len() + 1)
+    }
+
+    /// Check if a category is equal to or descended from another.
+    pub fn is_within(&self, category: &AudioCategory, ancestor: &AudioCategory) -> bool {
+        self.ancestors(category).any(|x| x == ancestor)
+    }
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct AudioCategoryInfo {
+    pub category: AudioCategory,
+    pub parent: Option<AudioCategory>,
+    /// The name to show in the settings menu.
+    pub label: String,
+}
+
+impl AudioCategoryInfo {
+    pub fn new(
+        category: AudioCategory,
+        parent: Option<AudioCategory>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            category,
+            parent,
+            label: label.into(),
+        }
+    }
+}
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct AudioSettings {
+    /// The volume setting of each [`AudioCategory`] relative to its parent, from 0 to 1.
+    pub volumes: BTreeMap<String, f32>,
+    /// The muted categories. Muting a category mutes its subcategories as well.
+    pub muted: HashSet<String>,
+    /// How volume settings map to gain.
+    pub curve: VolumeCurve,
+    /// How much to lower the master volume while the primary window is unfocused, in decibels.
+    ///
+    /// Use 0 to keep playing at full volume. This should be finite so the volume can fade in and
+    /// out, but 60 dB is already close to silent.
+    pub unfocused_decibels: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            volumes: BTreeMap::from([
+                ("master".to_string(), 0.9),
+                ("music".to_string(), 0.8),
+                ("sfx".to_string(), 0.8),
+                ("ui".to_string(), 0.8),
+            ]),
+            muted: default(),
+            curve: VolumeCurve::default(),
+            unfocused_decibels: 60.0,
+        }
+    }
+}
+
+impl Configure for AudioSettings {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+    }
+}
+
+impl AudioSettings {
+    /// The volume setting of a category relative to its parent.
+    pub fn volume(&self, category: &AudioCategory) -> f32 {
+        self.volumes.get(&*category.0).copied().unwrap_or(1.0)
+    }
+
+    pub fn set_volume(&mut self, category: &AudioCategory, volume: f32) {
+        self.volumes
+            .insert(category.0.to_string(), volume.clamp(0.0, 1.0));
+    }
+
+    pub fn is_muted(&self, category: &AudioCategory) -> bool {
+        self.muted.contains(&*category.0)
+    }
+
+    pub fn set_muted(&mut self, category: &AudioCategory, muted: bool) {
+        if muted {
+            self.muted.insert(category.0.to_string());
+        } else {
+            self.muted.remove(&*category.0);
+        }
+    }
+
+    /// The linear gain of a category relative to its parent, after the volume curve.
+    pub fn gain(&self, category: &AudioCategory) -> f32 {
+        if self.is_muted(category) {
+            0.0
+        } else {
+            self.curve.gain(self.volume(category))
+        }
+    }
+}
+
+/// How a volume setting from 0 to 1 maps to linear gain.
+#[derive(Reflect, Copy, Clone, PartialEq, Debug)]
+pub enum VolumeCurve {
+    /// Use the setting as the gain directly.
+    Linear,
+    /// Cube the setting, which roughly matches how loudness is perceived.
+    Perceptual,
+    /// Map the setting onto a range of decibels below full volume, with 0 as silence.
+    Decibels(f32),
+}
+
+impl Default for VolumeCurve {
+    fn default() -> Self {
+        Self::Decibels(40.0)
+    }
+}
+
+impl VolumeCurve {
+    pub fn gain(self, volume: f32) -> f32 {
+        let volume = volume.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => volume,
+            Self::Perceptual => volume.powi(3),
+            Self::Decibels(_) if volume <= 0.0 => 0.0,
+            Self::Decibels(range) => Volume::Decibels(range * (volume - 1.0)).to_linear(),
+        }
+    }
+}
+
+/// The final volume of each [`AudioCategory`], including its ancestors and [`AudioDucking`].
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct CategoryVolumes(HashMap<AudioCategory, f32>);
+
+impl Configure for CategoryVolumes {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            PostUpdate,
+            (
+                update_category_volumes.run_if(
+                    resource_changed::<AudioSettings>
+                        .or(resource_changed::<AudioCategories>)
+                        .or(resource_changed::<AudioDucking>),
+                ),
+                apply_category_volumes,
+            )
+                .chain()
+                .before(AudioPlaySet),
+        );
+    }
+}
+
+impl CategoryVolumes {
+    /// The final linear volume of a category, or the master volume if it's not registered.
+    pub fn get(&self, category: &AudioCategory) -> f32 {
+        self.0
+            .get(category)
+            .or_else(|| self.0.get(&AudioCategory::MASTER))
+            .copied()
+            .unwrap_or(1.0)
+    }
+}
+
+fn update_category_volumes(
+    categories: Res<AudioCategories>,
+    audio_settings: Res<AudioSettings>,
+    ducking: Res<AudioDucking>,
+    mut volumes: ResMut<CategoryVolumes>,
+) {
+    volumes.0 = categories
+        .0
+        .iter()
+        .map(|info| {
+            let volume = categories
+                .ancestors(&info.category)
+                .map(|x| audio_settings.gain(x) * ducking.gain(x))
+                .product();
+            (info.category.clone(), volume)
+        })
+        .collect();
+}
+
+fn apply_category_volumes(
+    volumes: Res<CategoryVolumes>,
+    // The music director and spatial SFX apply their own volumes.
+    mut audio_query: Query<
+        (
+            Ref<AudioCategory>,
+            Option<&Sound>,
+            Option<&mut PlaybackSettings>,
+            Option<&mut AudioSink>,
+        ),
+        (Without<DirectedMusic>, Without<SpatialSfx>),
+    >,
+) {
+    let apply_all = volumes.is_changed();
+    for (category, sound, playback, sink) in &mut audio_query {
+        cq!(apply_all || category.is_added());
+        let volume = Volume::Linear(volumes.get(&category) * sound.map_or(1.0, Sound::gain));
+
+        if let Some(mut sink) = sink {
+            sink.set_volume(volume);
+        } else if let Some(mut playback) = playback {
+            playback.volume = volume;
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub fn music_audio(handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        Name::new("MusicAudio"),
+        AudioCategory::MUSIC,
+        AudioPlayer(handle),
+        PlaybackSettings::LOOP,
+    )
+}
+
+#[allow(dead_code)]
+pub fn sfx_audio(handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        Name::new("SfxAudio"),
+        AudioCategory::SFX,
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN,
+    )
+}
+
+/// A sound effect that's panned and attenuated based on its position (see [`SpatialSfx`]).
+///
+/// Spawn this as a child of the entity that emits the sound.
+#[allow(dead_code)]
+pub fn spatial_sfx_audio(handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        Name::new("SpatialSfxAudio"),
+        AudioCategory::SFX,
+        SpatialSfx::default(),
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN.with_spatial(true),
+    )
+}
+
+#[allow(dead_code)]
+pub fn ui_audio(handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        Name::new("UiSample"),
+        AudioCategory::UI,
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_curve() {
+        for (curve, volume, want) in [
+            (VolumeCurve::Linear, 0.5, 0.5),
+            (VolumeCurve::Perceptual, 0.5, 0.125),
+            (VolumeCurve::Decibels(40.0), 0.0, 0.0),
+            (VolumeCurve::Decibels(40.0), 0.5, 0.1),
+            (VolumeCurve::Decibels(40.0), 1.0, 1.0),
+        ] {
+            let gain = curve.gain(volume);
+            assert!((gain - want).abs() < 1e-6, "{curve:?} at {volume}: {gain}");
+        }
+    }
+}
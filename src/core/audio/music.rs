@@ -0,0 +1,149 @@
+use crate::core::audio::AudioCategory;
+use crate::core::audio::CategoryVolumes;
+use crate::menu::Menu;
+use crate::prelude::*;
+use crate::screen::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(MusicDirector, DirectedMusic)>();
+}
+
+/// Chooses the music track to play for the current [`Screen`] and [`Menu`].
+///
+/// A menu's track takes priority over its screen's track.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct MusicDirector {
+    pub screen_tracks: HashMap<Screen, MusicTrack>,
+    pub menu_tracks: HashMap<Menu, MusicTrack>,
+    /// The duration of the crossfade between two tracks, in seconds.
+    pub crossfade_secs: f32,
+}
+
+impl Configure for MusicDirector {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(Update, direct_music.in_set(UpdateSystems::Update));
+    }
+}
+
+impl Default for MusicDirector {
+    fn default() -> Self {
+        Self {
+            screen_tracks: default(),
+            menu_tracks: default(),
+            crossfade_secs: 1.0,
+        }
+    }
+}
+
+impl MusicDirector {
+    fn track(&self, screen: Option<&Screen>, menu: Option<&Menu>) -> Option<&MusicTrack> {
+        menu.and_then(|x| self.menu_tracks.get(x))
+            .or_else(|| screen.and_then(|x| self.screen_tracks.get(x)))
+    }
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct MusicTrack {
+    pub handle: Handle<AudioSource>,
+    /// Resume from the last position instead of restarting when the track plays again.
+    pub resume: bool,
+}
+
+impl MusicTrack {
+    pub fn new(handle: Handle<AudioSource>) -> Self {
+        Self {
+            handle,
+            resume: false,
+        }
+    }
+
+    pub fn resume(mut self) -> Self {
+        self.resume = true;
+        self
+    }
+}
+
+/// A music track that's being faded in or out by the [`MusicDirector`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct DirectedMusic {
+    track: AssetId<AudioSource>,
+    resume: bool,
+    /// The current fade level, from 0 (silent) to 1 (full volume).
+    pub level: f32,
+}
+
+impl Configure for DirectedMusic {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+fn direct_music(
+    mut commands: Commands,
+    time: Res<Time>,
+    director: Res<MusicDirector>,
+    volumes: Res<CategoryVolumes>,
+    screen: CurrentRef<Screen>,
+    menu: CurrentRef<Menu>,
+    mut music_query: Query<(Entity, &mut DirectedMusic, Option<&mut AudioSink>)>,
+) {
+    let volume = volumes.get(&AudioCategory::MUSIC);
+    let track = director.track(screen.get(), menu.get());
+    let track_id = track.map(|x| x.handle.id());
+    let step = if director.crossfade_secs > 0.0 {
+        time.delta_secs() / director.crossfade_secs
+    } else {
+        1.0
+    };
+
+    let mut is_playing = false;
+    for (entity, mut music, sink) in &mut music_query {
+        let fade_in = Some(music.track) == track_id;
+        is_playing |= fade_in;
+
+        // Step the crossfade.
+        music.level = if fade_in {
+            (music.level + step).min(1.0)
+        } else {
+            (music.level - step).max(0.0)
+        };
+
+        // Stop or pause a track that has faded out.
+        if !fade_in && music.level <= 0.0 {
+            if !music.resume {
+                commands.entity(entity).despawn();
+            } else if let Some(sink) = &sink {
+                sink.pause();
+            }
+            continue;
+        }
+
+        let mut sink = cq!(sink);
+        sink.play();
+        sink.set_volume(Volume::Linear(volume * music.level));
+    }
+
+    // Start a new track.
+    let track = rq!(track);
+    rq!(!is_playing);
+    let level = if director.crossfade_secs > 0.0 {
+        0.0
+    } else {
+        1.0
+    };
+    commands.spawn((
+        Name::new("MusicAudio"),
+        AudioCategory::MUSIC,
+        DirectedMusic {
+            track: track.handle.id(),
+            resume: track.resume,
+            level,
+        },
+        AudioPlayer(track.handle.clone()),
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(volume * level)),
+    ));
+}
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use bevy::asset::LoadContext;
+use bevy::audio::PlaybackMode;
+
+use crate::core::audio::AudioCategory;
+use crate::core::audio::spatial::SpatialSfx;
+use crate::core::audio::voice::VoiceLimit;
+use crate::core::audio::voice::VoiceLimits;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(ConfigHandle<AudioConfig>, Sound)>();
+}
+
+/// Sound settings.
+#[derive(Asset, Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AudioConfig {
+    /// Named sound events, played with [`Sound`].
+    pub sounds: BTreeMap<String, SoundDef>,
+}
+
+impl Config for AudioConfig {
+    const FILE: &'static str = "audio.ron";
+
+    fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = vec![];
+
+        for (name, sound) in &self.sounds {
+            if sound.clips.is_empty() {
+                errors.push(ConfigError::new(
+                    format!("sounds[{name}].clips"),
+                    "must not be empty",
+                ));
+            }
+            let (min, max) = sound.volume;
+            if !(0.0 <= min && min <= max && max.is_finite()) {
+                errors.push(ConfigError::new(
+                    format!("sounds[{name}].volume"),
+                    "must be a non-negative (min, max) range with min <= max",
+                ));
+            }
+            let (min, max) = sound.pitch;
+            if !(0.0 < min && min <= max && max.is_finite()) {
+                errors.push(ConfigError::new(
+                    format!("sounds[{name}].pitch"),
+                    "must be a positive (min, max) range with min <= max",
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn load_assets(&mut self, load_context: &mut LoadContext) {
+        for sound in self.sounds.values_mut() {
+            for clip in &mut sound.clips {
+                clip.load(load_context);
+            }
+        }
+    }
+
+    fn on_load(&self, world: &mut World, _changed: &ConfigChanged<Self>) {
+        let mut limits = r!(world.get_resource_mut::<VoiceLimits>());
+        limits.events = self
+            .sounds
+            .iter()
+            .filter_map(|(name, sound)| Some((name.clone(), sound.voice_limit?)))
+            .collect();
+    }
+}
+
+/// The definition of a named sound event.
+#[derive(Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SoundDef {
+    /// The clips to choose from at random.
+    pub clips: Vec<ConfigAsset<AudioSource>>,
+    pub category: AudioCategory,
+    /// The (min, max) range of the random volume scale.
+    #[serde(default = "default_range")]
+    pub volume: (f32, f32),
+    /// The (min, max) range of the random playback speed.
+    #[serde(default = "default_range")]
+    pub pitch: (f32, f32),
+    /// Whether to loop instead of despawning when finished.
+    #[serde(default, rename = "loop")]
+    pub looping: bool,
+    /// A limit on how many instances of this sound can play at once.
+    #[serde(default)]
+    pub voice_limit: Option<VoiceLimit>,
+}
+
+fn default_range() -> (f32, f32) {
+    (1.0, 1.0)
+}
+
+/// Play a named sound event from the [`AudioConfig`].
+///
+/// Add [`SpatialSfx`] as well to play the sound positionally.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Sound {
+    pub name: String,
+    /// The random volume scale chosen for this instance.
+    gain: f32,
+}
+
+impl Configure for Sound {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_observer(play_sound);
+    }
+}
+
+impl Sound {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            gain: 1.0,
+        }
+    }
+
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+}
+
+fn play_sound(
+    trigger: Trigger<OnAdd, Sound>,
+    mut commands: Commands,
+    config: ConfigRef<AudioConfig>,
+    mut sound_query: Query<(&mut Sound, Has<SpatialSfx>)>,
+) {
+    let entity = trigger.target();
+    let (mut sound, is_spatial) = r!(sound_query.get_mut(entity));
+    let Some(config) = config.get() else {
+        warn!(
+            "Sound played before the audio config loaded: {}",
+            sound.name
+        );
+        commands.entity(entity).despawn();
+        return;
+    };
+    let Some(def) = config.sounds.get(&sound.name) else {
+        warn!("Unknown sound: {}", sound.name);
+        commands.entity(entity).despawn();
+        return;
+    };
+    let mut rng = thread_rng();
+    let clip = r!(def.clips.choose(&mut rng));
+    sound.gain = sample_range(&mut rng, def.volume);
+    commands.entity(entity).insert((
+        Name::new(format!("Sound({})", sound.name)),
+        def.category.clone(),
+        AudioPlayer(clip.handle().clone()),
+        PlaybackSettings {
+            mode: if def.looping {
+                PlaybackMode::Loop
+            } else {
+                PlaybackMode::Despawn
+            },
+            speed: sample_range(&mut rng, def.pitch),
+            spatial: is_spatial,
+            ..default()
+        },
+    ));
+}
+
+/// Sample a random value from a (min, max) range, tolerating an inverted range.
+fn sample_range(rng: &mut impl Rng, (a, b): (f32, f32)) -> f32 {
+    rng.gen_range(a.min(b)..=a.max(b))
+}
@@ -0,0 +1,165 @@
+use bevy::audio::AudioPlaySet;
+use bevy::transform::TransformSystem;
+
+use crate::core::audio::AudioCategory;
+use crate::core::audio::CategoryVolumes;
+use crate::core::audio::sound::Sound;
+use crate::core::camera::PrimaryCamera;
+use crate::core::physics::PIXELS_PER_METER;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<SpatialSfx>();
+}
+
+/// Pan and attenuate a sound based on its position relative to the [`PrimaryCamera`].
+///
+/// Distances are in meters (see [`PIXELS_PER_METER`]).
+#[derive(Component, Reflect, Copy, Clone, Debug)]
+#[reflect(Component)]
+#[require(Transform)]
+pub struct SpatialSfx {
+    /// The distance within which the sound plays at full volume.
+    pub min_distance: f32,
+    /// The distance beyond which the sound is culled.
+    pub max_distance: f32,
+    /// How the volume falls off between the min and max distance.
+    pub rolloff: Rolloff,
+    /// The horizontal distance at which the sound is panned fully to one side.
+    pub pan_distance: f32,
+}
+
+impl Configure for SpatialSfx {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            (
+                cull_spatial_sfx
+                    .after(TransformSystem::TransformPropagate)
+                    .before(AudioPlaySet),
+                // Override the emitter and ear positions that Bevy sets in `AudioPlaySet`.
+                apply_spatial_sfx.after(AudioPlaySet),
+            ),
+        );
+    }
+}
+
+impl Default for SpatialSfx {
+    fn default() -> Self {
+        Self {
+            min_distance: 2.0,
+            max_distance: 40.0,
+            rolloff: Rolloff::InverseDistance,
+            pan_distance: 20.0,
+        }
+    }
+}
+
+impl SpatialSfx {
+    /// The linear gain at a distance from the listener.
+    pub fn gain(&self, distance: f32) -> f32 {
+        if distance >= self.max_distance {
+            return 0.0;
+        }
+        if distance <= self.min_distance {
+            return 1.0;
+        }
+
+        match self.rolloff {
+            Rolloff::Linear => {
+                1.0 - (distance - self.min_distance) / (self.max_distance - self.min_distance)
+            },
+            Rolloff::InverseDistance => self.min_distance / distance,
+            Rolloff::InverseSquare => (self.min_distance / distance).powi(2),
+        }
+    }
+
+    /// The pan at a horizontal offset from the listener, from -1 (left) to 1 (right).
+    pub fn pan(&self, offset: f32) -> f32 {
+        if self.pan_distance <= 0.0 {
+            return 0.0;
+        }
+
+        (offset / self.pan_distance).clamp(-1.0, 1.0)
+    }
+}
+
+#[derive(Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Rolloff {
+    Linear,
+    InverseDistance,
+    InverseSquare,
+}
+
+/// The listener-relative offset in meters.
+fn listener_offset(camera: &GlobalTransform, emitter: &GlobalTransform) -> Vec2 {
+    camera
+        .affine()
+        .inverse()
+        .transform_point3(emitter.translation())
+        .xy()
+        / PIXELS_PER_METER
+}
+
+/// Despawn sounds that would start playing beyond their max distance.
+fn cull_spatial_sfx(
+    mut commands: Commands,
+    camera: Single<&GlobalTransform, With<PrimaryCamera>>,
+    sfx_query: Query<
+        (Entity, &SpatialSfx, &GlobalTransform),
+        (With<AudioPlayer>, Without<SpatialAudioSink>),
+    >,
+) {
+    for (entity, sfx, gt) in &sfx_query {
+        if listener_offset(&camera, gt).length() >= sfx.max_distance {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+fn apply_spatial_sfx(
+    camera: Single<&GlobalTransform, With<PrimaryCamera>>,
+    volumes: Res<CategoryVolumes>,
+    mut sfx_query: Query<(
+        &SpatialSfx,
+        &AudioCategory,
+        &GlobalTransform,
+        &mut SpatialAudioSink,
+        Option<&Sound>,
+    )>,
+) {
+    for (sfx, category, gt, mut sink, sound) in &mut sfx_query {
+        let offset = listener_offset(&camera, gt);
+        let gain = sfx.gain(offset.length()) * sound.map_or(1.0, Sound::gain);
+        sink.set_volume(Volume::Linear(volumes.get(category) * gain));
+
+        // Use unit distances so that rodio only pans, without attenuating.
+        sink.set_ears_position(vec3(-0.5, 0.0, 0.0), vec3(0.5, 0.0, 0.0));
+        sink.set_emitter_position(vec3(0.5 * sfx.pan(offset.x), 0.0, 0.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gain() {
+        for (rolloff, distance, want) in [
+            (Rolloff::Linear, 0.0, 1.0),
+            (Rolloff::Linear, 21.0, 0.5),
+            (Rolloff::Linear, 40.0, 0.0),
+            (Rolloff::InverseDistance, 1.0, 1.0),
+            (Rolloff::InverseDistance, 4.0, 0.5),
+            (Rolloff::InverseSquare, 4.0, 0.25),
+            (Rolloff::InverseSquare, 100.0, 0.0),
+        ] {
+            let sfx = SpatialSfx {
+                rolloff,
+                ..default()
+            };
+            assert_eq!(sfx.gain(distance), want, "{rolloff:?} at {distance}");
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use std::f32::consts::TAU;
+
+use bevy::asset::AssetLoader;
+use bevy::asset::LoadContext;
+use bevy::asset::io::Reader;
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SynthPreset>();
+    app.register_asset_loader(SynthLoader);
+}
+
+/// The sample rate of synthesized sounds, in Hz.
+pub const SYNTH_SAMPLE_RATE: u32 = 44100;
+
+/// An sfxr-style retro sound effect, loaded as an [`AudioSource`] from a `.sfx.ron` file.
+///
+/// Frequencies are in Hz, times are in seconds, and pitch changes are in octaves.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct SynthPreset {
+    pub waveform: Waveform,
+    pub envelope: Envelope,
+    /// The starting frequency.
+    pub frequency: f32,
+    /// The frequency below which the sound cuts off early.
+    pub min_frequency: f32,
+    /// The change in pitch per second.
+    pub slide: f32,
+    /// The change in slide per second.
+    pub delta_slide: f32,
+    /// The vibrato depth.
+    pub vibrato_depth: f32,
+    /// The vibrato frequency.
+    pub vibrato_speed: f32,
+    /// The pitch change after the arpeggio delay.
+    pub arpeggio: f32,
+    pub arpeggio_delay: f32,
+    /// The fraction of each period that a square wave is high, from 0 to 1.
+    pub duty: f32,
+    /// The change in duty per second.
+    pub duty_slide: f32,
+    /// The seed for [`Waveform::Noise`].
+    pub seed: u64,
+    pub volume: f32,
+}
+
+impl Default for SynthPreset {
+    fn default() -> Self {
+        Self {
+            waveform: Waveform::Square,
+            envelope: Envelope::default(),
+            frequency: 440.0,
+            min_frequency: 0.0,
+            slide: 0.0,
+            delta_slide: 0.0,
+            vibrato_depth: 0.0,
+            vibrato_speed: 0.0,
+            arpeggio: 0.0,
+            arpeggio_delay: 0.0,
+            duty: 0.5,
+            duty_slide: 0.0,
+            seed: 0,
+            volume: 0.5,
+        }
+    }
+}
+
+impl SynthPreset {
+    /// Render the sound into mono samples from -1 to 1.
+    pub fn render(&self, sample_rate: u32) -> Vec<f32> {
+        let dt = 1.0 / sample_rate as f32;
+        let len = (self.envelope.duration() * sample_rate as f32).round() as usize;
+        let mut samples = Vec::with_capacity(len);
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut noise = rng.gen_range(-1.0..=1.0);
+        let mut phase = 0.0f32;
+        let mut pitch = 0.0;
+        let mut slide = self.slide;
+
+        for i in 0..len {
+            let t = i as f32 * dt;
+            slide += self.delta_slide * dt;
+            pitch += slide * dt;
+            let mut octaves = pitch + self.vibrato_depth * (TAU * self.vibrato_speed * t).sin();
+            if self.arpeggio_delay > 0.0 && t >= self.arpeggio_delay {
+                octaves += self.arpeggio;
+            }
+            let frequency = self.frequency * octaves.exp2();
+            if frequency < self.min_frequency {
+                break;
+            }
+
+            // Pick a new noise value every half period.
+            let prev_phase = phase;
+            phase = (phase + frequency * dt).fract();
+            if phase < prev_phase || (prev_phase < 0.5 && phase >= 0.5) {
+                noise = rng.gen_range(-1.0..=1.0);
+            }
+
+            let sample = match self.waveform {
+                Waveform::Square => {
+                    let duty = (self.duty + self.duty_slide * t).clamp(0.0, 1.0);
+                    if phase < duty { 1.0 } else { -1.0 }
+                },
+                Waveform::Sawtooth => 1.0 - 2.0 * phase,
+                Waveform::Triangle => 4.0 * (phase - 0.5).abs() - 1.0,
+                Waveform::Sine => (TAU * phase).sin(),
+                Waveform::Noise => noise,
+            };
+            samples.push((sample * self.envelope.gain(t) * self.volume).clamp(-1.0, 1.0));
+        }
+
+        samples
+    }
+
+    /// Render the sound into an [`AudioSource`].
+    pub fn to_audio_source(&self) -> AudioSource {
+        AudioSource {
+            bytes: encode_wav(&self.render(SYNTH_SAMPLE_RATE), SYNTH_SAMPLE_RATE).into(),
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Waveform {
+    Square,
+    Sawtooth,
+    Triangle,
+    Sine,
+    Noise,
+}
+
+/// A volume envelope that rises, holds, then falls.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Envelope {
+    pub attack: f32,
+    pub sustain: f32,
+    /// The extra volume at the start of the sustain, fading out over the sustain.
+    pub punch: f32,
+    pub decay: f32,
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        Self {
+            attack: 0.0,
+            sustain: 0.1,
+            punch: 0.0,
+            decay: 0.2,
+        }
+    }
+}
+
+impl Envelope {
+    pub fn duration(&self) -> f32 {
+        self.attack + self.sustain + self.decay
+    }
+
+    /// The linear gain at a time since the sound started.
+    pub fn gain(&self, t: f32) -> f32 {
+        if t < self.attack {
+            return t / self.attack;
+        }
+        let t = t - self.attack;
+        if t < self.sustain {
+            return 1.0 + self.punch * (1.0 - t / self.sustain);
+        }
+        let t = t - self.sustain;
+        if t < self.decay {
+            return 1.0 - t / self.decay;
+        }
+
+        0.0
+    }
+}
+
+/// Encode mono samples as a 16-bit PCM WAV file.
+fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_len = 2 * samples.len() as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend(b"RIFF");
+    bytes.extend((36 + data_len).to_le_bytes());
+    bytes.extend(b"WAVEfmt ");
+    // Format chunk: PCM, 1 channel, sample rate, byte rate, block align, bits per sample.
+    bytes.extend(16u32.to_le_bytes());
+    bytes.extend(1u16.to_le_bytes());
+    bytes.extend(1u16.to_le_bytes());
+    bytes.extend(sample_rate.to_le_bytes());
+    bytes.extend((2 * sample_rate).to_le_bytes());
+    bytes.extend(2u16.to_le_bytes());
+    bytes.extend(16u16.to_le_bytes());
+    bytes.extend(b"data");
+    bytes.extend(data_len.to_le_bytes());
+    for &sample in samples {
+        bytes.extend(((sample * i16::MAX as f32) as i16).to_le_bytes());
+    }
+
+    bytes
+}
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Loads a [`SynthPreset`] and renders it into an [`AudioSource`].
+struct SynthLoader;
+
+impl AssetLoader for SynthLoader {
+    type Asset = AudioSource;
+    type Settings = ();
+    type Error = BoxedError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let preset = ron::de::from_bytes::<SynthPreset>(&bytes)?;
+        Ok(preset.to_audio_source())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sfx.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        for waveform in [
+            Waveform::Square,
+            Waveform::Sawtooth,
+            Waveform::Triangle,
+            Waveform::Sine,
+            Waveform::Noise,
+        ] {
+            let preset = SynthPreset {
+                waveform,
+                envelope: Envelope {
+                    attack: 0.01,
+                    sustain: 0.1,
+                    punch: 0.5,
+                    decay: 0.1,
+                },
+                volume: 1.0,
+                ..default()
+            };
+            let samples = preset.render(1000);
+            assert_eq!(samples.len(), 210, "{waveform:?}");
+            assert!(samples.iter().all(|x| x.abs() <= 1.0), "{waveform:?}");
+            assert!(samples.iter().any(|x| x.abs() > 0.5), "{waveform:?}");
+            assert_eq!(samples, preset.render(1000), "{waveform:?}");
+        }
+    }
+
+    #[test]
+    fn test_min_frequency() {
+        let preset = SynthPreset {
+            frequency: 400.0,
+            min_frequency: 110.0,
+            // Fall 20 octaves per second, which crosses 110 Hz after ~0.093 seconds.
+            slide: -20.0,
+            ..default()
+        };
+        assert_eq!(preset.render(1000).len(), 93);
+    }
+
+    #[test]
+    fn test_encode_wav() {
+        let bytes = encode_wav(&[0.0, 1.0, -1.0], 8000);
+        assert_eq!(bytes.len(), 44 + 6);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(&bytes[8..16], b"WAVEfmt ");
+        assert_eq!(&bytes[44..], [0, 0, 0xff, 0x7f, 0x01, 0x80]);
+    }
+}
@@ -0,0 +1,323 @@
+use bevy::audio::AudioPlaySet;
+
+use crate::core::audio::AudioCategory;
+use crate::core::audio::sound::Sound;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(VoiceLimits, Voice)>();
+}
+
+/// Limits on the number of sounds that can play at once.
+///
+/// New sounds are checked against these limits right before they start playing.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct VoiceLimits {
+    pub categories: HashMap<AudioCategory, VoiceLimit>,
+    pub sounds: HashMap<AssetId<AudioSource>, VoiceLimit>,
+    /// Limits for named sound events (see `AudioConfig`).
+    pub events: HashMap<String, VoiceLimit>,
+    /// When each category or sound last started playing.
+    #[reflect(ignore)]
+    last_started: HashMap<VoiceKey, Duration>,
+}
+
+impl Configure for VoiceLimits {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(PostUpdate, limit_voices.before(AudioPlaySet));
+    }
+}
+
+impl VoiceLimits {
+    /// Check a new voice against each limit that applies to it, stealing voices if allowed.
+    ///
+    /// Returns the indices of the voices to steal, or `None` if the new voice is rejected.
+    fn admit(
+        &mut self,
+        keys: &[VoiceKey],
+        voices: &[VoiceInfo],
+        now: Duration,
+    ) -> Option<Vec<usize>> {
+        let mut stolen = vec![];
+        for key in keys {
+            let limit = *c!(match key {
+                VoiceKey::Category(x) => self.categories.get(x),
+                VoiceKey::Sound(x) => self.sounds.get(x),
+                VoiceKey::Event(x) => self.events.get(x),
+            });
+
+            if self
+                .last_started
+                .get(key)
+                .is_some_and(|&x| (now - x).as_secs_f32() < limit.min_interval)
+            {
+                return None;
+            }
+
+            let matching = voices
+                .iter()
+                .enumerate()
+                .filter(|(i, x)| x.matches(key) && !stolen.contains(i));
+            let excess = (matching.clone().count() + 1).saturating_sub(limit.max_voices);
+            if excess == 0 {
+                continue;
+            }
+
+            let mut candidates = matching.collect::<Vec<_>>();
+            match limit.steal {
+                StealPolicy::Oldest => candidates.sort_by_key(|(_, x)| x.started),
+                StealPolicy::Quietest => {
+                    candidates.sort_by(|(_, a), (_, b)| a.volume.total_cmp(&b.volume))
+                },
+                StealPolicy::Reject => return None,
+            }
+            stolen.extend(candidates.into_iter().take(excess).map(|(i, _)| i));
+        }
+
+        for key in keys {
+            self.last_started.insert(key.clone(), now);
+        }
+        Some(stolen)
+    }
+}
+
+impl Default for VoiceLimits {
+    fn default() -> Self {
+        Self {
+            categories: HashMap::from([
+                (
+                    AudioCategory::SFX,
+                    VoiceLimit::new(16, StealPolicy::Quietest),
+                ),
+                (AudioCategory::UI, VoiceLimit::new(4, StealPolicy::Oldest)),
+            ]),
+            sounds: default(),
+            events: default(),
+            last_started: default(),
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct VoiceLimit {
+    pub max_voices: usize,
+    pub steal: StealPolicy,
+    /// The minimum time between two sounds starting, in seconds.
+    #[serde(default)]
+    pub min_interval: f32,
+}
+
+impl VoiceLimit {
+    pub fn new(max_voices: usize, steal: StealPolicy) -> Self {
+        Self {
+            max_voices,
+            steal,
+            min_interval: 0.0,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_min_interval(mut self, secs: f32) -> Self {
+        self.min_interval = secs;
+        self
+    }
+}
+
+/// What to do when a new sound would exceed a [`VoiceLimit`].
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum StealPolicy {
+    /// Stop the oldest playing sound.
+    Oldest,
+    /// Stop the quietest playing sound.
+    Quietest,
+    /// Don't play the new sound.
+    Reject,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum VoiceKey {
+    Category(AudioCategory),
+    Sound(AssetId<AudioSource>),
+    Event(String),
+}
+
+/// A sound that was admitted by the [`VoiceLimits`].
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Voice {
+    category: AudioCategory,
+    sound: AssetId<AudioSource>,
+    event: Option<String>,
+    started: Duration,
+}
+
+impl Configure for Voice {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+    }
+}
+
+struct VoiceInfo {
+    entity: Entity,
+    category: AudioCategory,
+    sound: AssetId<AudioSource>,
+    event: Option<String>,
+    started: Duration,
+    volume: f32,
+}
+
+impl VoiceInfo {
+    fn matches(&self, key: &VoiceKey) -> bool {
+        match key {
+            VoiceKey::Category(x) => self.category == *x,
+            VoiceKey::Sound(x) => self.sound == *x,
+            VoiceKey::Event(x) => self.event.as_ref() == Some(x),
+        }
+    }
+}
+
+fn limit_voices(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut limits: ResMut<VoiceLimits>,
+    new_query: Query<
+        (
+            Entity,
+            &AudioPlayer,
+            &PlaybackSettings,
+            &AudioCategory,
+            Option<&Sound>,
+        ),
+        (
+            Without<Voice>,
+            Without<AudioSink>,
+            Without<SpatialAudioSink>,
+        ),
+    >,
+    voice_query: Query<(
+        Entity,
+        &Voice,
+        &PlaybackSettings,
+        Option<&AudioSink>,
+        Option<&SpatialAudioSink>,
+    )>,
+) {
+    rq!(!new_query.is_empty());
+    let now = time.elapsed();
+    let mut voices = voice_query
+        .iter()
+        .map(|(entity, voice, playback, sink, spatial_sink)| VoiceInfo {
+            entity,
+            category: voice.category.clone(),
+            sound: voice.sound,
+            event: voice.event.clone(),
+            started: voice.started,
+            volume: sink
+                .map(|x| x.volume())
+                .or_else(|| spatial_sink.map(|x| x.volume()))
+                .unwrap_or(playback.volume)
+                .to_linear(),
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, player, playback, category, event) in &new_query {
+        let sound = player.0.id();
+        let event = event.map(|x| x.name.clone());
+        let mut keys = vec![VoiceKey::Category(category.clone()), VoiceKey::Sound(sound)];
+        keys.extend(event.clone().map(VoiceKey::Event));
+
+        let Some(mut stolen) = limits.admit(&keys, &voices, now) else {
+            commands.entity(entity).try_despawn();
+            continue;
+        };
+
+        // Admit the new voice.
+        stolen.sort_unstable();
+        for i in stolen.into_iter().rev() {
+            commands.entity(voices.swap_remove(i).entity).try_despawn();
+        }
+        commands.entity(entity).insert(Voice {
+            category: category.clone(),
+            sound,
+            event: event.clone(),
+            started: now,
+        });
+        voices.push(VoiceInfo {
+            entity,
+            category: category.clone(),
+            sound,
+            event,
+            started: now,
+            volume: playback.volume.to_linear(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voice(started: u64, volume: f32) -> VoiceInfo {
+        VoiceInfo {
+            entity: Entity::PLACEHOLDER,
+            category: AudioCategory::SFX,
+            sound: default(),
+            event: None,
+            started: Duration::from_secs(started),
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_steal_policy() {
+        for (max_voices, steal, want) in [
+            (4, StealPolicy::Reject, Some(vec![])),
+            (3, StealPolicy::Oldest, Some(vec![1])),
+            (2, StealPolicy::Oldest, Some(vec![1, 0])),
+            (3, StealPolicy::Quietest, Some(vec![2])),
+            (2, StealPolicy::Quietest, Some(vec![2, 0])),
+            (3, StealPolicy::Reject, None),
+        ] {
+            let mut limits = VoiceLimits {
+                categories: HashMap::from([(
+                    AudioCategory::SFX,
+                    VoiceLimit::new(max_voices, steal),
+                )]),
+                ..default()
+            };
+            let voices = [voice(2, 0.5), voice(1, 1.0), voice(3, 0.25)];
+            let keys = [VoiceKey::Category(AudioCategory::SFX)];
+            assert_eq!(
+                limits.admit(&keys, &voices, Duration::from_secs(4)),
+                want,
+                "{steal:?} with {max_voices} max voices",
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_interval() {
+        for (elapsed, want) in [(0.05, false), (0.15, true), (1.0, true)] {
+            let mut limits = VoiceLimits {
+                categories: HashMap::from([(
+                    AudioCategory::SFX,
+                    VoiceLimit::new(16, StealPolicy::Oldest).with_min_interval(0.1),
+                )]),
+                ..default()
+            };
+            let keys = [VoiceKey::Category(AudioCategory::SFX)];
+            assert!(limits.admit(&keys, &[], Duration::ZERO).is_some());
+            assert_eq!(
+                limits
+                    .admit(&keys, &[], Duration::from_secs_f32(elapsed))
+                    .is_some(),
+                want,
+                "{elapsed}s after the last sound",
+            );
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use bevy::window::PrimaryWindow;
+
+use crate::core::camera::PrimaryCamera;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<CursorPosition>();
+}
+
+/// The position of the cursor relative to the [`PrimaryCamera`], updated every frame.
+///
+/// Positions are `None` while the cursor is outside of the primary window.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct CursorPosition {
+    /// The logical position in the primary window.
+    pub window: Option<Vec2>,
+    /// The logical position relative to the top-left corner of the camera's viewport.
+    ///
+    /// UI nodes are laid out in the same coordinates while UI is confined to the viewport.
+    pub viewport: Option<Vec2>,
+    /// The world position, accounting for the camera's zoom.
+    pub world: Option<Vec2>,
+    /// Whether the cursor is inside the (possibly letterboxed) viewport.
+    pub in_viewport: bool,
+}
+
+impl Configure for CursorPosition {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.init_resource::<Self>();
+        app.add_systems(
+            Update,
+            update_cursor_position.in_set(UpdateSystems::SyncEarly),
+        );
+    }
+}
+
+fn update_cursor_position(
+    primary_window: Single<&Window, With<PrimaryWindow>>,
+    primary_camera: Single<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+    mut cursor: ResMut<CursorPosition>,
+) {
+    let (camera, camera_gt) = *primary_camera;
+    let window_pos = primary_window.cursor_position();
+    let viewport_rect = camera.logical_viewport_rect();
+    let viewport_pos = window_pos
+        .zip(viewport_rect)
+        .map(|(pos, rect)| pos - rect.min);
+
+    *cursor = CursorPosition {
+        window: window_pos,
+        viewport: viewport_pos,
+        world: window_pos.and_then(|pos| ui_to_world(camera, camera_gt, pos, false)),
+        in_viewport: window_pos.is_some_and(|pos| in_viewport(camera, pos)),
+    };
+}
+
+/// Whether a logical window position is inside the camera's (possibly letterboxed) viewport.
+pub fn in_viewport(camera: &Camera, pos: Vec2) -> bool {
+    camera
+        .logical_viewport_rect()
+        .is_some_and(|rect| rect.contains(pos))
+}
+
+/// Convert a logical UI position into a world position.
+///
+/// UI positions are relative to the window, or to the camera's viewport if UI is `confined` to it.
+pub fn ui_to_world(
+    camera: &Camera,
+    camera_gt: &GlobalTransform,
+    pos: Vec2,
+    confined: bool,
+) -> Option<Vec2> {
+    let rect = camera.logical_viewport_rect()?;
+    let pos = if confined { pos } else { pos - rect.min };
+    let size = rect.size();
+    let ndc = vec2(2.0 * pos.x / size.x - 1.0, 1.0 - 2.0 * pos.y / size.y);
+    Some(camera.ndc_to_world(camera_gt, ndc.extend(0.0))?.xy())
+}
+
+/// Convert a world position into a logical UI position (see [`ui_to_world`]).
+#[allow(dead_code)]
+pub fn world_to_ui(
+    camera: &Camera,
+    camera_gt: &GlobalTransform,
+    pos: Vec2,
+    confined: bool,
+) -> Option<Vec2> {
+    let rect = camera.logical_viewport_rect()?;
+    let ndc = camera.world_to_ndc(camera_gt, pos.extend(0.0))?.xy();
+    let pos = vec2(ndc.x + 1.0, 1.0 - ndc.y) / 2.0 * rect.size();
+    Some(if confined { pos } else { pos + rect.min })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+    use bevy::render::camera::ManualTextureViews;
+    use bevy::render::camera::Viewport;
+    use bevy::render::camera::camera_system;
+    use bevy::window::WindowCreated;
+    use bevy::window::WindowResized;
+    use bevy::window::WindowResolution;
+    use bevy::window::WindowScaleFactorChanged;
+
+    use super::*;
+
+    /// A 16:9 letterbox in an 800x600 physical window.
+    const LETTERBOX: Viewport = Viewport {
+        physical_position: UVec2::new(0, 75),
+        physical_size: UVec2::new(800, 450),
+        depth: 0.0..1.0,
+    };
+
+    /// Compute a camera in an 800x600 physical window at 2x scale (400x300 logical).
+    fn camera(viewport: Option<Viewport>, zoom: f32) -> Camera {
+        let mut world = World::new();
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ManualTextureViews>();
+        world.spawn((
+            Window {
+                resolution: WindowResolution::new(800.0, 600.0).with_scale_factor_override(2.0),
+                ..default()
+            },
+            PrimaryWindow,
+        ));
+        let camera = world
+            .spawn((
+                Camera {
+                    viewport,
+                    ..default()
+                },
+                Projection::Orthographic(OrthographicProjection {
+                    scale: zoom.recip(),
+                    ..OrthographicProjection::default_2d()
+                }),
+            ))
+            .id();
+        world.run_system_once(camera_system).unwrap();
+        world.get::<Camera>(camera).unwrap().clone()
+    }
+
+    #[test]
+    fn test_ui_to_world() {
+        for (viewport, confined, zoom, camera_pos, ui_pos, want) in [
+            (None, false, 1.0, Vec2::ZERO, vec2(200.0, 150.0), Vec2::ZERO),
+            (
+                None,
+                false,
+                1.0,
+                Vec2::ZERO,
+                vec2(0.0, 0.0),
+                vec2(-200.0, 150.0),
+            ),
+            (
+                None,
+                true,
+                2.0,
+                Vec2::ZERO,
+                vec2(0.0, 0.0),
+                vec2(-100.0, 75.0),
+            ),
+            (
+                None,
+                false,
+                1.0,
+                vec2(10.0, 20.0),
+                vec2(400.0, 300.0),
+                vec2(210.0, -130.0),
+            ),
+            (
+                Some(LETTERBOX),
+                true,
+                1.0,
+                Vec2::ZERO,
+                vec2(0.0, 0.0),
+                vec2(-200.0, 112.5),
+            ),
+            (
+                Some(LETTERBOX),
+                true,
+                2.0,
+                Vec2::ZERO,
+                vec2(200.0, 225.0),
+                vec2(0.0, -56.25),
+            ),
+            (
+                Some(LETTERBOX),
+                false,
+                1.0,
+                Vec2::ZERO,
+                vec2(0.0, 37.5),
+                vec2(-200.0, 112.5),
+            ),
+            (
+                Some(LETTERBOX),
+                false,
+                2.0,
+                Vec2::ZERO,
+                vec2(200.0, 262.5),
+                vec2(0.0, -56.25),
+            ),
+        ] {
+            let camera = camera(viewport.clone(), zoom);
+            let camera_gt = GlobalTransform::from_translation(camera_pos.extend(0.0));
+            let world_pos = ui_to_world(&camera, &camera_gt, ui_pos, confined).unwrap();
+            assert!(
+                world_pos.abs_diff_eq(want, 1e-3),
+                "{ui_pos} at zoom {zoom} (confined: {confined}): got {world_pos}, want {want}",
+            );
+            let round_trip = world_to_ui(&camera, &camera_gt, world_pos, confined).unwrap();
+            assert!(
+                round_trip.abs_diff_eq(ui_pos, 1e-3),
+                "{ui_pos} at zoom {zoom} (confined: {confined}): round trip got {round_trip}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_viewport() {
+        for (viewport, pos, want) in [
+            (None, vec2(200.0, 20.0), true),
+            (None, vec2(401.0, 150.0), false),
+            (Some(LETTERBOX), vec2(200.0, 150.0), true),
+            (Some(LETTERBOX), vec2(0.0, 37.5), true),
+            (Some(LETTERBOX), vec2(200.0, 20.0), false),
+            (Some(LETTERBOX), vec2(200.0, 280.0), false),
+        ] {
+            assert_eq!(
+                in_viewport(&camera(viewport.clone(), 1.0), pos),
+                want,
+                "{pos} with viewport {viewport:?}",
+            );
+        }
+    }
+}
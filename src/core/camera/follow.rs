@@ -0,0 +1,167 @@
+use crate::core::camera::framing::GroupFraming;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<SmoothFollow>();
+}
+
+/// Follow a target entity smoothly.
+///
+/// This component should only be used on root entities.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct SmoothFollow {
+    /// The entity to follow, or [`Entity::PLACEHOLDER`] to stay in place.
+    pub target: Entity,
+    /// How quickly to catch up to the target, per second.
+    pub rate: Vec2,
+    /// The size of the rectangle around the camera that the target can move in freely.
+    pub deadzone: Vec2,
+    /// How far ahead of the target to look based on its [`LinearVelocity`], in seconds.
+    pub lookahead: f32,
+    /// The maximum lookahead distance.
+    pub max_lookahead: f32,
+    /// The world-space rectangle that the camera's view must stay within, if any.
+    pub bounds: Option<Rect>,
+}
+
+impl Configure for SmoothFollow {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(Update, apply_smooth_follow.in_set(PausableSystems));
+    }
+}
+
+impl Default for SmoothFollow {
+    fn default() -> Self {
+        Self {
+            target: Entity::PLACEHOLDER,
+            rate: Vec2::splat(100.0),
+            deadzone: Vec2::ZERO,
+            lookahead: 0.0,
+            max_lookahead: 0.0,
+            bounds: None,
+        }
+    }
+}
+
+impl SmoothFollow {
+    /// Start following a new target entity.
+    #[allow(dead_code)]
+    pub fn retarget(&mut self, target: Entity) {
+        self.target = target;
+    }
+
+    /// Stop following any target.
+    #[allow(dead_code)]
+    pub fn clear_target(&mut self) {
+        self.target = Entity::PLACEHOLDER;
+    }
+
+    /// The position to move towards, given the current position and the target's position
+    /// and velocity.
+    fn goal(&self, pos: Vec2, target_pos: Vec2, target_velocity: Vec2) -> Vec2 {
+        let lookahead = (target_velocity * self.lookahead).clamp_length_max(self.max_lookahead);
+        let delta = target_pos + lookahead - pos;
+        let half_deadzone = self.deadzone / 2.0;
+        pos + delta - delta.clamp(-half_deadzone, half_deadzone)
+    }
+
+    /// Clamp a position so that a view rectangle around it stays within the bounds.
+    pub(super) fn clamp_to_bounds(&self, pos: Vec2, view: Rect) -> Vec2 {
+        let Some(bounds) = self.bounds else {
+            return pos;
+        };
+
+        let min = bounds.min - view.min;
+        let max = bounds.max - view.max;
+        // Center the view if it's larger than the bounds.
+        vec2(
+            if min.x <= max.x {
+                pos.x.clamp(min.x, max.x)
+            } else {
+                (min.x + max.x) / 2.0
+            },
+            if min.y <= max.y {
+                pos.y.clamp(min.y, max.y)
+            } else {
+                (min.y + max.y) / 2.0
+            },
+        )
+    }
+}
+
+pub(super) fn apply_smooth_follow(
+    time: Res<Time>,
+    mut follow_query: Query<(
+        &mut Transform,
+        &SmoothFollow,
+        Option<&Projection>,
+        Option<&GroupFraming>,
+    )>,
+    target_query: Query<(&GlobalTransform, Option<&LinearVelocity>), Without<SmoothFollow>>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, follow, projection, framing) in &mut follow_query {
+        // Group framing takes over while it's active.
+        cq!(!framing.is_some_and(GroupFraming::is_active));
+
+        let mut pos = transform.translation.xy();
+        if let Ok((target_gt, target_velocity)) = target_query.get(follow.target) {
+            let target_pos = target_gt.translation().xy();
+            let target_velocity = target_velocity.map_or(Vec2::ZERO, |x| x.0);
+            let goal = follow.goal(pos, target_pos, target_velocity);
+            pos += (goal - pos) * (follow.rate * dt).clamp(Vec2::ZERO, Vec2::ONE);
+        }
+
+        let view = match projection {
+            Some(Projection::Orthographic(x)) => x.area,
+            _ => Rect::default(),
+        };
+        pos = follow.clamp_to_bounds(pos, view);
+        transform.translation = pos.extend(transform.translation.z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal() {
+        let follow = SmoothFollow {
+            deadzone: vec2(20.0, 10.0),
+            lookahead: 0.5,
+            max_lookahead: 8.0,
+            ..default()
+        };
+        for (target_pos, target_velocity, want) in [
+            (vec2(5.0, 5.0), Vec2::ZERO, Vec2::ZERO),
+            (vec2(15.0, -5.0), Vec2::ZERO, vec2(5.0, 0.0)),
+            (vec2(0.0, 0.0), vec2(0.0, 100.0), vec2(0.0, 3.0)),
+        ] {
+            assert_eq!(
+                follow.goal(Vec2::ZERO, target_pos, target_velocity),
+                want,
+                "{target_pos} at {target_velocity}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_clamp_to_bounds() {
+        let follow = SmoothFollow {
+            bounds: Some(Rect::new(0.0, 0.0, 100.0, 50.0)),
+            ..default()
+        };
+        let view = Rect::new(-20.0, -30.0, 20.0, 30.0);
+        assert_eq!(
+            follow.clamp_to_bounds(vec2(0.0, 0.0), view),
+            vec2(20.0, 25.0)
+        );
+        assert_eq!(
+            follow.clamp_to_bounds(vec2(90.0, 10.0), view),
+            vec2(80.0, 25.0)
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::core::camera::follow::SmoothFollow;
+use crate::core::camera::pixel::PixelPerfect;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<GroupFraming>();
+}
+
+/// Keep a group of weighted targets on screen by moving and zooming a camera.
+///
+/// While there are any targets, this overrides the target of [`SmoothFollow`] but still
+/// respects its bounds. The zoom is fit to the projection area, which already accounts for
+/// a [`Letterbox`](super::Letterbox) viewport.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct GroupFraming {
+    pub targets: Vec<FramingTarget>,
+    /// The extra space to keep around each target, in world units.
+    pub padding: Vec2,
+    /// How quickly to catch up to the framing position, per second.
+    pub rate: Vec2,
+    /// How quickly to catch up to the framing zoom, per second.
+    pub zoom_rate: f32,
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    /// The zoom to return to when there are no targets.
+    pub default_zoom: f32,
+    /// Whether the zoom is still returning to the default zoom.
+    settling: bool,
+}
+
+impl Configure for GroupFraming {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            apply_group_framing
+                .after(super::follow::apply_smooth_follow)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+impl Default for GroupFraming {
+    fn default() -> Self {
+        Self {
+            targets: vec![],
+            padding: Vec2::splat(32.0),
+            rate: Vec2::splat(5.0),
+            zoom_rate: 3.0,
+            min_zoom: 0.5,
+            max_zoom: 2.0,
+            default_zoom: 1.0,
+            settling: false,
+        }
+    }
+}
+
+impl GroupFraming {
+    pub fn is_active(&self) -> bool {
+        !self.targets.is_empty()
+    }
+
+    /// Add a target, or update its weight if it's already a target.
+    #[allow(dead_code)]
+    pub fn add_target(&mut self, entity: Entity, weight: f32) {
+        match self.targets.iter_mut().find(|x| x.entity == entity) {
+            Some(target) => target.weight = weight,
+            None => self.targets.push(FramingTarget { entity, weight }),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn remove_target(&mut self, entity: Entity) {
+        self.targets.retain(|x| x.entity != entity);
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_targets(&mut self) {
+        self.targets.clear();
+    }
+
+    /// The weighted center of the targets, and the half-size needed to fit them all around it.
+    fn frame(&self, targets: &[(Vec2, f32)]) -> Option<(Vec2, Vec2)> {
+        rq!(!targets.is_empty());
+
+        let total_weight = targets.iter().map(|(_, weight)| weight).sum::<f32>();
+        let center = if total_weight > 0.0 {
+            targets
+                .iter()
+                .map(|&(pos, weight)| pos * weight)
+                .sum::<Vec2>()
+                / total_weight
+        } else {
+            targets.iter().map(|(pos, _)| pos).sum::<Vec2>() / targets.len() as f32
+        };
+        let half_size = targets
+            .iter()
+            .map(|(pos, _)| (*pos - center).abs())
+            .fold(Vec2::ZERO, Vec2::max)
+            + self.padding;
+
+        Some((center, half_size))
+    }
+
+    /// The zoom that fits a half-size into a view of a given size at zoom 1.
+    fn fit_zoom(&self, half_size: Vec2, view_size: Vec2) -> f32 {
+        let zoom = (view_size / (2.0 * half_size)).min_element();
+        if zoom.is_finite() {
+            zoom.clamp(self.min_zoom, self.max_zoom)
+        } else {
+            self.max_zoom
+        }
+    }
+}
+
+#[derive(Reflect, Copy, Clone, Debug)]
+pub struct FramingTarget {
+    pub entity: Entity,
+    /// How strongly the target pulls the center of the frame towards itself.
+    pub weight: f32,
+}
+
+pub(super) fn apply_group_framing(
+    time: Res<Time>,
+    mut camera_query: Query<(
+        &mut Transform,
+        &mut Projection,
+        &mut GroupFraming,
+        Option<&SmoothFollow>,
+        Has<PixelPerfect>,
+    )>,
+    target_query: Query<&GlobalTransform, Without<GroupFraming>>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut projection, mut framing, follow, is_pixel_perfect) in &mut camera_query
+    {
+        let Projection::Orthographic(projection) = projection.as_mut() else {
+            continue;
+        };
+        let zoom = projection.scale.recip();
+        let view_size = projection.area.size() * zoom;
+
+        let targets = framing
+            .targets
+            .iter()
+            .filter_map(|x| {
+                Some((
+                    target_query.get(x.entity).ok()?.translation().xy(),
+                    x.weight,
+                ))
+            })
+            .collect::<Vec<_>>();
+        let goal_pos;
+        let goal_zoom;
+        if let Some((center, half_size)) = framing.frame(&targets) {
+            framing.settling = true;
+            goal_pos = Some(center);
+            goal_zoom = framing.fit_zoom(half_size, view_size);
+        } else if framing.settling {
+            goal_pos = None;
+            goal_zoom = framing.default_zoom;
+        } else {
+            continue;
+        }
+
+        let mut new_zoom = if is_pixel_perfect {
+            // Jump straight to a whole zoom that still fits the targets (see `PixelPerfect`).
+            goal_zoom.floor().max(1.0)
+        } else {
+            // Approach the goal zoom exponentially, since zoom is multiplicative.
+            let t = (framing.zoom_rate * dt).clamp(0.0, 1.0);
+            zoom * (goal_zoom / zoom).powf(t)
+        };
+        if goal_pos.is_none() && (new_zoom - goal_zoom).abs() < 1e-3 {
+            new_zoom = goal_zoom;
+            framing.settling = false;
+        }
+        projection.scale = new_zoom.recip();
+
+        let mut pos = transform.translation.xy();
+        if let Some(goal_pos) = goal_pos {
+            pos += (goal_pos - pos) * (framing.rate * dt).clamp(Vec2::ZERO, Vec2::ONE);
+        }
+        if let Some(follow) = follow {
+            let view = Rect {
+                min: projection.area.min * zoom / new_zoom,
+                max: projection.area.max * zoom / new_zoom,
+            };
+            pos = follow.clamp_to_bounds(pos, view);
+        }
+        transform.translation = pos.extend(transform.translation.z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let framing = GroupFraming {
+            padding: Vec2::splat(10.0),
+            ..default()
+        };
+        assert_eq!(framing.frame(&[]), None);
+        assert_eq!(
+            framing.frame(&[(vec2(-20.0, 0.0), 1.0), (vec2(20.0, 10.0), 1.0)]),
+            Some((vec2(0.0, 5.0), vec2(30.0, 15.0))),
+        );
+        assert_eq!(
+            framing.frame(&[(vec2(0.0, 0.0), 3.0), (vec2(40.0, 0.0), 1.0)]),
+            Some((vec2(10.0, 0.0), vec2(40.0, 10.0))),
+        );
+    }
+
+    #[test]
+    fn test_fit_zoom() {
+        let framing = GroupFraming::default();
+        let view_size = vec2(320.0, 180.0);
+        assert_eq!(framing.fit_zoom(vec2(80.0, 20.0), view_size), 2.0);
+        assert_eq!(framing.fit_zoom(vec2(80.0, 90.0), view_size), 1.0);
+        assert_eq!(framing.fit_zoom(vec2(1000.0, 20.0), view_size), 0.5);
+        assert_eq!(framing.fit_zoom(Vec2::ZERO, view_size), 2.0);
+    }
+}
@@ -0,0 +1,532 @@
+pub mod cursor;
+pub mod follow;
+pub mod framing;
+pub mod motion;
+pub mod pixel;
+pub mod shake;
+
+use bevy::render::camera::CameraMainTextureUsages;
+use bevy::render::camera::CameraOutputMode;
+use bevy::render::camera::ScalingMode;
+use bevy::render::camera::Viewport;
+use bevy::render::render_resource::BlendState;
+use bevy::render::render_resource::TextureUsages;
+use bevy::render::view::RenderLayers;
+use bevy::window::PrimaryWindow;
+use bevy::window::WindowResized;
+use bevy::window::WindowScaleFactorChanged;
+
+use crate::core::camera::follow::SmoothFollow;
+use crate::core::camera::framing::GroupFraming;
+use crate::core::camera::motion::CameraMove;
+use crate::core::camera::motion::CameraMoves;
+use crate::core::camera::pixel::PixelPerfect;
+use crate::core::camera::shake::CameraShake;
+use crate::prelude::*;
+use crate::theme::color::ColorMut;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        ConfigHandle<CameraConfig>,
+        PrimaryCamera,
+        UiCamera,
+        Letterbox,
+        LetterboxColor,
+        AbsoluteScale,
+    )>();
+
+    app.add_plugins((
+        cursor::plugin,
+        follow::plugin,
+        framing::plugin,
+        motion::plugin,
+        pixel::plugin,
+        shake::plugin,
+    ));
+}
+
+/// Primary camera settings.
+#[derive(Asset, Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CameraConfig {
+    /// How the projection scales with the window size.
+    scaling_mode: ScalingMode,
+    /// The projection scale factor. Higher values zoom in.
+    zoom: f32,
+    /// The aspect ratio to letterbox the viewport to, if any.
+    aspect_ratio: Option<f32>,
+    /// The virtual resolution to render at with integer upscaling, if any (see [`PixelPerfect`]).
+    ///
+    /// This overrides the scaling mode, and letterboxes on its own.
+    pixel_perfect: Option<(u32, u32)>,
+    /// Whether to lay out UI within the letterboxed viewport instead of the whole window.
+    confine_ui: bool,
+    /// The color of the letterbox bars.
+    letterbox_color: ThemeColor,
+    /// How the camera follows its target (see [`SmoothFollow`]).
+    follow: FollowConfig,
+    /// How the camera frames a group of targets (see [`GroupFraming`]).
+    framing: FramingConfig,
+    /// The duration of the camera reset between screens, in seconds (see [`CameraMoves`]).
+    reset_secs: f32,
+}
+
+#[derive(Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+struct FollowConfig {
+    /// How quickly to catch up to the target, per second.
+    rate: (f32, f32),
+    /// The size of the rectangle around the camera that the target can move in freely.
+    deadzone: (f32, f32),
+    /// How far ahead of the target to look based on its velocity, in seconds.
+    lookahead: f32,
+    /// The maximum lookahead distance.
+    max_lookahead: f32,
+    /// The world-space (min x, min y, max x, max y) that the camera's view must stay within.
+    bounds: Option<(f32, f32, f32, f32)>,
+}
+
+#[derive(Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+struct FramingConfig {
+    /// The extra space to keep around each target, in world units.
+    padding: (f32, f32),
+    /// How quickly to catch up to the framing position, per second.
+    rate: (f32, f32),
+    /// How quickly to catch up to the framing zoom, per second.
+    zoom_rate: f32,
+    /// The zoom limits while framing.
+    min_zoom: f32,
+    max_zoom: f32,
+}
+
+impl Config for CameraConfig {
+    const FILE: &'static str = "camera.ron";
+
+    fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = vec![];
+
+        if !(self.zoom.is_finite() && self.zoom > 0.0) {
+            errors.push(ConfigError::new("zoom", "must be positive"));
+        }
+        if let Some(aspect_ratio) = self.aspect_ratio {
+            if !(aspect_ratio.is_finite() && aspect_ratio > 0.0) {
+                errors.push(ConfigError::new("aspect_ratio", "must be positive"));
+            }
+        }
+        if let Some((width, height)) = self.pixel_perfect {
+            if width == 0 || height == 0 {
+                errors.push(ConfigError::new("pixel_perfect", "must be positive"));
+            }
+            if self.aspect_ratio.is_some() {
+                errors.push(ConfigError::new(
+                    "pixel_perfect",
+                    "must not be set with aspect_ratio",
+                ));
+            }
+            for (field, x) in [
+                ("zoom", self.zoom),
+                ("framing.min_zoom", self.framing.min_zoom),
+                ("framing.max_zoom", self.framing.max_zoom),
+            ] {
+                if x != PixelPerfect::snap_zoom(x) {
+                    errors.push(ConfigError::new(
+                        field,
+                        "must be a positive whole number with pixel_perfect",
+                    ));
+                }
+            }
+        }
+        for (field, (x, y)) in [
+            ("follow.rate", self.follow.rate),
+            ("follow.deadzone", self.follow.deadzone),
+            ("framing.padding", self.framing.padding),
+            ("framing.rate", self.framing.rate),
+        ] {
+            if !(x.is_finite() && x >= 0.0 && y.is_finite() && y >= 0.0) {
+                errors.push(ConfigError::new(field, "must be non-negative"));
+            }
+        }
+        for (field, x) in [
+            ("follow.lookahead", self.follow.lookahead),
+            ("follow.max_lookahead", self.follow.max_lookahead),
+            ("framing.zoom_rate", self.framing.zoom_rate),
+            ("reset_secs", self.reset_secs),
+        ] {
+            if !(x.is_finite() && x >= 0.0) {
+                errors.push(ConfigError::new(field, "must be non-negative"));
+            }
+        }
+        if let Some((min_x, min_y, max_x, max_y)) = self.follow.bounds {
+            if !(min_x <= max_x && min_y <= max_y) {
+                errors.push(ConfigError::new("follow.bounds", "must have min <= max"));
+            }
+        }
+        if !(self.framing.min_zoom.is_finite() && self.framing.min_zoom > 0.0) {
+            errors.push(ConfigError::new("framing.min_zoom", "must be positive"));
+        }
+        if !(self.framing.max_zoom.is_finite() && self.framing.max_zoom >= self.framing.min_zoom) {
+            errors.push(ConfigError::new(
+                "framing.max_zoom",
+                "must be at least min_zoom",
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn on_load(&self, world: &mut World, changed: &ConfigChanged<Self>) {
+        let (entity, mut camera, projection, mut follow, mut framing, mut moves) = r!(world
+            .query_filtered::<(
+                Entity,
+                &mut Camera,
+                &mut Projection,
+                &mut SmoothFollow,
+                &mut GroupFraming,
+                &mut CameraMoves,
+            ), With<PrimaryCamera>>()
+            .single_mut(world));
+
+        if changed.is_changed("follow") {
+            follow.rate = self.follow.rate.into();
+            follow.deadzone = self.follow.deadzone.into();
+            follow.lookahead = self.follow.lookahead;
+            follow.max_lookahead = self.follow.max_lookahead;
+            follow.bounds = self
+                .follow
+                .bounds
+                .map(|(min_x, min_y, max_x, max_y)| Rect::new(min_x, min_y, max_x, max_y));
+        }
+
+        if changed.is_changed("framing") || changed.is_changed("zoom") {
+            framing.padding = self.framing.padding.into();
+            framing.rate = self.framing.rate.into();
+            framing.zoom_rate = self.framing.zoom_rate;
+            framing.min_zoom = self.framing.min_zoom;
+            framing.max_zoom = self.framing.max_zoom;
+            framing.default_zoom = self.zoom;
+        }
+
+        if changed.is_changed("zoom") || changed.is_changed("reset_secs") {
+            moves.reset = CameraMove::to(Vec2::ZERO)
+                .with_zoom(self.zoom)
+                .over(self.reset_secs)
+                .interrupting();
+        }
+
+        if changed.is_changed("zoom")
+            || changed.is_changed("scaling_mode")
+            || changed.is_changed("pixel_perfect")
+        {
+            let projection = r!(match projection.into_inner() {
+                Projection::Orthographic(x) => Some(x),
+                _ => None,
+            });
+            projection.scale = self.zoom.recip();
+            projection.scaling_mode = match self.pixel_perfect {
+                Some((width, height)) => ScalingMode::Fixed {
+                    width: width as f32,
+                    height: height as f32,
+                },
+                None => self.scaling_mode,
+            };
+        }
+
+        if self.aspect_ratio.is_none() && self.pixel_perfect.is_none() {
+            camera.viewport = None;
+        }
+
+        let mut entity = r!(world.get_entity_mut(entity));
+        if changed.is_changed("letterbox_color") {
+            entity.insert(self.letterbox_color.set::<LetterboxColor>());
+        }
+        if changed.is_changed("aspect_ratio") || changed.is_changed("pixel_perfect") {
+            match self.aspect_ratio {
+                Some(aspect_ratio) => {
+                    entity
+                        .entry::<Letterbox>()
+                        .and_modify(|mut letterbox| letterbox.aspect_ratio = aspect_ratio)
+                        .or_insert(Letterbox { aspect_ratio });
+                },
+                None => {
+                    entity.remove::<Letterbox>();
+                },
+            }
+            match self.pixel_perfect {
+                Some((width, height)) => {
+                    entity.insert(PixelPerfect {
+                        resolution: UVec2::new(width, height),
+                    });
+                },
+                None => {
+                    entity.remove::<PixelPerfect>();
+                },
+            }
+        }
+        rq!(changed.is_changed("confine_ui"));
+        if self.confine_ui {
+            entity.insert(IsDefaultUiCamera);
+        } else {
+            entity.remove::<IsDefaultUiCamera>();
+        }
+
+        let (entity, mut camera) = r!(world
+            .query_filtered::<(Entity, &mut Camera), With<UiCamera>>()
+            .single_mut(world));
+        camera.is_active = !self.confine_ui;
+        let mut entity = r!(world.get_entity_mut(entity));
+        if self.confine_ui {
+            entity.remove::<IsDefaultUiCamera>();
+        } else {
+            entity.insert(IsDefaultUiCamera);
+        }
+    }
+}
+
+/// A marker component for the primary camera.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct PrimaryCamera;
+
+impl Configure for PrimaryCamera {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(Startup, spawn_primary_camera);
+    }
+}
+
+fn spawn_primary_camera(mut commands: Commands) {
+    commands.spawn((
+        Name::new("PrimaryCamera"),
+        PrimaryCamera,
+        IsDefaultUiCamera,
+        Camera2d,
+        LetterboxColor::default(),
+        ThemeColor::Letterbox.set::<LetterboxColor>(),
+        Projection::Orthographic(OrthographicProjection {
+            near: -1000.0,
+            ..OrthographicProjection::default_2d()
+        }),
+        Msaa::Off,
+        SpatialListener::new(1.0),
+        CameraShake::default(),
+        SmoothFollow::default(),
+        GroupFraming::default(),
+        CameraMoves::default(),
+    ));
+}
+
+/// A marker component for the camera that renders UI over the whole window.
+///
+/// This camera is only active when UI isn't confined to the [`PrimaryCamera`] viewport.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct UiCamera;
+
+impl Configure for UiCamera {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(Startup, spawn_ui_camera);
+    }
+}
+
+fn spawn_ui_camera(mut commands: Commands) {
+    commands.spawn((
+        Name::new("UiCamera"),
+        UiCamera,
+        Camera2d,
+        Camera {
+            // Render on top of the primary camera.
+            order: 1,
+            is_active: false,
+            clear_color: ClearColorConfig::Custom(Color::NONE),
+            // Blend over the primary camera's output without clearing its letterbox bars.
+            output_mode: CameraOutputMode::Write {
+                blend_state: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                clear_color: ClearColorConfig::None,
+            },
+            ..default()
+        },
+        // Render to a separate main texture. Otherwise this camera would share the primary
+        // camera's main texture, which is cleared to the `ClearColor` outside its viewport too.
+        CameraMainTextureUsages(CameraMainTextureUsages::default().0 | TextureUsages::COPY_DST),
+        // Only render UI.
+        RenderLayers::none(),
+        Msaa::Off,
+    ));
+}
+
+/// Letterbox a camera's viewport to a particular aspect ratio.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Letterbox {
+    pub aspect_ratio: f32,
+}
+
+impl Configure for Letterbox {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            apply_letterbox.run_if(
+                any_match_filter::<Changed<Letterbox>>
+                    .or(on_event::<WindowResized>)
+                    .or(on_event::<WindowScaleFactorChanged>),
+            ),
+        );
+    }
+}
+
+fn apply_letterbox(
+    mut letterbox_query: Query<(&mut Camera, &Letterbox)>,
+    primary_window: Single<&Window, With<PrimaryWindow>>,
+) {
+    for (mut camera, letterbox) in &mut letterbox_query {
+        let window_width = primary_window.physical_width() as f32;
+        let window_height = primary_window.physical_height() as f32;
+        let mut size = vec2(window_width, window_height);
+        let mut pos = Vec2::ZERO;
+
+        if window_width / window_height > letterbox.aspect_ratio {
+            size.x = (size.y * letterbox.aspect_ratio).max(1.0);
+            pos.x = window_width / 2.0 - size.x / 2.0;
+        } else {
+            size.y = (size.x / letterbox.aspect_ratio).max(1.0);
+            pos.y = window_height / 2.0 - size.y / 2.0;
+        }
+
+        camera.viewport = Some(Viewport {
+            physical_position: pos.as_uvec2(),
+            physical_size: size.as_uvec2(),
+            ..default()
+        });
+    }
+}
+
+/// The color that the area outside of a camera's viewport (e.g. letterbox bars) is cleared to.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct LetterboxColor(pub Color);
+
+impl Configure for LetterboxColor {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.configure::<ThemeColorFor<Self>>();
+        app.add_systems(PostUpdate, apply_letterbox_color);
+    }
+}
+
+impl Default for LetterboxColor {
+    fn default() -> Self {
+        Self(Color::BLACK)
+    }
+}
+
+impl ColorMut for LetterboxColor {
+    fn color_mut(&mut self) -> &mut Color {
+        &mut self.0
+    }
+}
+
+fn apply_letterbox_color(
+    mut camera_query: Query<(&mut Camera, &LetterboxColor), Changed<LetterboxColor>>,
+) {
+    for (mut camera, letterbox_color) in &mut camera_query {
+        // Keep the blend state, and leave cameras that skip output alone.
+        if let CameraOutputMode::Write { clear_color, .. } = &mut camera.output_mode {
+            *clear_color = ClearColorConfig::Custom(letterbox_color.0);
+        }
+    }
+}
+
+// TODO: Workaround for <https://github.com/bevyengine/bevy/issues/1890>.
+/// Camera zoom-independent scale.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct AbsoluteScale(pub Vec3);
+
+impl Configure for AbsoluteScale {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(Update, apply_absolute_scale.in_set(UpdateSystems::SyncLate));
+    }
+}
+
+impl Default for AbsoluteScale {
+    fn default() -> Self {
+        Self(Vec3::ONE)
+    }
+}
+
+fn apply_absolute_scale(
+    primary_camera: Single<(&Projection, &Camera), With<PrimaryCamera>>,
+    mut scale_query: Query<(&mut Transform, &AbsoluteScale)>,
+) {
+    let (projection, camera) = *primary_camera;
+    let projection = r!(match projection {
+        Projection::Orthographic(x) => Some(x),
+        _ => None,
+    });
+    let viewport_size = r!(camera.logical_viewport_size());
+    let units_per_pixel = projection.area.width() / viewport_size.x;
+    let camera_scale_inverse = Vec2::splat(units_per_pixel).extend(1.0);
+
+    for (mut transform, scale) in &mut scale_query {
+        transform.scale = camera_scale_inverse * scale.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test::TestApp;
+
+    #[test]
+    fn test_unconfined_ui_camera_keeps_letterbox() {
+        let mut app = TestApp::default();
+        app.step_until("UI camera", |app| {
+            let world = app.0.world();
+            world
+                .try_query_filtered::<&Camera, With<UiCamera>>()
+                .and_then(|mut query| query.single(world).ok().map(|x| x.is_active))
+                .unwrap_or(false)
+        });
+
+        let world = app.0.world_mut();
+        let (primary_camera, primary_usages) = world
+            .query_filtered::<(&Camera, &CameraMainTextureUsages), With<PrimaryCamera>>()
+            .single(world)
+            .unwrap();
+        let primary_output_mode = primary_camera.output_mode;
+        let primary_usages = primary_usages.0;
+        let (ui_camera, ui_usages) = world
+            .query_filtered::<(&Camera, &CameraMainTextureUsages), With<UiCamera>>()
+            .single(world)
+            .unwrap();
+
+        // The primary camera clears the letterbox bars, and the UI camera blends over them.
+        assert!(matches!(
+            primary_output_mode,
+            CameraOutputMode::Write {
+                clear_color: ClearColorConfig::Custom(_),
+                ..
+            },
+        ));
+        assert!(matches!(
+            ui_camera.output_mode,
+            CameraOutputMode::Write {
+                blend_state: Some(_),
+                clear_color: ClearColorConfig::None,
+            },
+        ));
+        assert!(matches!(ui_camera.clear_color, ClearColorConfig::Custom(x) if x.alpha() == 0.0));
+        assert_ne!(
+            ui_usages.0, primary_usages,
+            "UI camera shares a main texture"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::VecDeque;
+
+use bevy::math::curve::Curve;
+use bevy::math::curve::EaseFunction;
+use bevy::math::curve::EasingCurve;
+
+use crate::core::camera::PrimaryCamera;
+use crate::core::camera::framing::apply_group_framing;
+use crate::core::camera::pixel::PixelPerfect;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(CameraMove, CameraMoves)>();
+}
+
+/// An animated move to a position and zoom.
+///
+/// Send this as an event to queue a move for the [`PrimaryCamera`].
+#[derive(Event, Reflect, Clone, Debug)]
+pub struct CameraMove {
+    /// The position to move to, or `None` to leave the position alone.
+    pub position: Option<Vec2>,
+    /// The zoom to move to, or `None` to leave the zoom alone.
+    pub zoom: Option<f32>,
+    /// The duration of the move, in seconds.
+    pub duration: f32,
+    pub ease: EaseFunction,
+    /// Whether to cancel the active and queued moves instead of waiting for them.
+    pub interrupt: bool,
+}
+
+impl Configure for CameraMove {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_event::<Self>();
+    }
+}
+
+impl Default for CameraMove {
+    fn default() -> Self {
+        Self {
+            position: None,
+            zoom: None,
+            duration: 1.0,
+            ease: EaseFunction::CubicInOut,
+            interrupt: false,
+        }
+    }
+}
+
+impl CameraMove {
+    pub fn to(position: Vec2) -> Self {
+        Self {
+            position: Some(position),
+            ..default()
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn zoom_to(zoom: f32) -> Self {
+        Self {
+            zoom: Some(zoom),
+            ..default()
+        }
+    }
+
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    pub fn over(mut self, secs: f32) -> Self {
+        self.duration = secs;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    pub fn interrupting(mut self) -> Self {
+        self.interrupt = true;
+        self
+    }
+}
+
+/// The active and queued [`CameraMove`]s of a camera.
+///
+/// While a move is active, it overrides [`SmoothFollow`](super::follow::SmoothFollow) and
+/// [`GroupFraming`](super::framing::GroupFraming).
+///
+/// Moves keep playing while the game is paused, so that the reset between screens also runs
+/// on paused screens like the title screen.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct CameraMoves {
+    /// The move to use when resetting the camera between screens.
+    pub reset: CameraMove,
+    queue: VecDeque<CameraMove>,
+    active: Option<ActiveMove>,
+}
+
+impl Configure for CameraMoves {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            (queue_camera_moves, apply_camera_moves)
+                .chain()
+                .after(apply_group_framing)
+                .in_set(UpdateSystems::Update),
+        );
+    }
+}
+
+impl Default for CameraMoves {
+    fn default() -> Self {
+        Self {
+            // Reset instantly until `CameraConfig` sets the reset duration.
+            reset: CameraMove::to(Vec2::ZERO)
+                .with_zoom(1.0)
+                .over(0.0)
+                .interrupting(),
+            queue: default(),
+            active: None,
+        }
+    }
+}
+
+impl CameraMoves {
+    pub fn push(&mut self, camera_move: CameraMove) {
+        if camera_move.interrupt {
+            self.clear();
+        }
+        self.queue.push_back(camera_move);
+    }
+
+    /// Queue the [`Self::reset`] move.
+    pub fn push_reset(&mut self) {
+        self.push(self.reset.clone());
+    }
+
+    /// Cancel the active and queued moves, leaving the camera where it is.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.active = None;
+    }
+
+    #[allow(dead_code)]
+    pub fn is_moving(&self) -> bool {
+        self.active.is_some() || !self.queue.is_empty()
+    }
+
+    /// Advance the active move, starting the next queued move from the given position and
+    /// zoom if there is none.
+    ///
+    /// Returns the new position and zoom, or `None` for each one that the move leaves alone.
+    fn tick(&mut self, dt: f32, position: Vec2, zoom: f32) -> (Option<Vec2>, Option<f32>) {
+        if self.active.is_none() {
+            let Some(camera_move) = self.queue.pop_front() else {
+                return (None, None);
+            };
+            self.active = Some(ActiveMove {
+                camera_move,
+                from_position: position,
+                from_zoom: zoom,
+                elapsed: 0.0,
+            });
+        }
+        let Some(active) = self.active.as_mut() else {
+            return (None, None);
+        };
+
+        active.elapsed += dt;
+        let camera_move = &active.camera_move;
+        let t = if camera_move.duration > 0.0 {
+            active.elapsed / camera_move.duration
+        } else {
+            1.0
+        };
+        let t = EasingCurve::new(0.0, 1.0, camera_move.ease).sample_clamped(t);
+
+        let position = camera_move
+            .position
+            .map(|x| active.from_position.lerp(x, t));
+        // Interpolate exponentially, since zoom is multiplicative.
+        let zoom = camera_move
+            .zoom
+            .map(|x| active.from_zoom * (x / active.from_zoom).powf(t));
+
+        if active.elapsed >= camera_move.duration {
+            self.active = None;
+        }
+        (position, zoom)
+    }
+}
+
+#[derive(Reflect, Debug)]
+struct ActiveMove {
+    camera_move: CameraMove,
+    from_position: Vec2,
+    from_zoom: f32,
+    elapsed: f32,
+}
+
+fn queue_camera_moves(
+    mut move_events: EventReader<CameraMove>,
+    mut moves_query: Query<&mut CameraMoves, With<PrimaryCamera>>,
+) {
+    for event in move_events.read() {
+        for mut moves in &mut moves_query {
+            moves.push(event.clone());
+        }
+    }
+}
+
+fn apply_camera_moves(
+    time: Res<Time>,
+    mut camera_query: Query<(
+        &mut Transform,
+        &mut Projection,
+        &mut CameraMoves,
+        Has<PixelPerfect>,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut projection, mut moves, is_pixel_perfect) in &mut camera_query {
+        let Projection::Orthographic(projection) = projection.as_mut() else {
+            continue;
+        };
+        let (position, zoom) = moves.tick(dt, transform.translation.xy(), projection.scale.recip());
+
+        if let Some(position) = position {
+            transform.translation = position.extend(transform.translation.z);
+        }
+        if let Some(mut zoom) = zoom {
+            if is_pixel_perfect {
+                zoom = PixelPerfect::snap_zoom(zoom);
+            }
+            projection.scale = zoom.recip();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick() {
+        let mut moves = CameraMoves::default();
+        assert_eq!(moves.tick(0.5, Vec2::ZERO, 1.0), (None, None));
+
+        moves.push(
+            CameraMove::to(vec2(10.0, 0.0))
+                .over(2.0)
+                .with_ease(EaseFunction::Linear),
+        );
+        moves.push(CameraMove::zoom_to(4.0).with_ease(EaseFunction::Linear));
+        for (dt, position, zoom, want) in [
+            // The first move starts from the current position.
+            (0.5, vec2(0.0, 0.0), 1.0, (Some(vec2(2.5, 0.0)), None)),
+            (1.0, vec2(2.5, 0.0), 1.0, (Some(vec2(7.5, 0.0)), None)),
+            (0.5, vec2(7.5, 0.0), 1.0, (Some(vec2(10.0, 0.0)), None)),
+            // The second move starts from where the first one ended.
+            (0.5, vec2(10.0, 0.0), 1.0, (None, Some(2.0))),
+            (0.5, vec2(10.0, 0.0), 2.0, (None, Some(4.0))),
+            // The queue is empty.
+            (0.5, vec2(10.0, 0.0), 4.0, (None, None)),
+        ] {
+            assert_eq!(
+                moves.tick(dt, position, zoom),
+                want,
+                "{dt}s from {position} at zoom {zoom}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_interrupting() {
+        for (interrupt, want) in [
+            (false, (Some(vec2(10.0, 0.0)), None)),
+            (true, (None, Some(2.0))),
+        ] {
+            let mut moves = CameraMoves::default();
+            moves.push(CameraMove::to(vec2(10.0, 0.0)).over(1.0));
+            moves.tick(0.5, Vec2::ZERO, 1.0);
+
+            let mut camera_move = CameraMove::zoom_to(2.0).over(0.0);
+            camera_move.interrupt = interrupt;
+            moves.push(camera_move);
+            assert_eq!(
+                moves.tick(0.5, vec2(5.0, 0.0), 1.0),
+                want,
+                "interrupt: {interrupt}",
+            );
+            assert_eq!(moves.is_moving(), !interrupt, "interrupt: {interrupt}");
+        }
+    }
+}
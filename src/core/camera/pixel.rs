@@ -0,0 +1,138 @@
+use bevy::render::camera::Viewport;
+use bevy::window::PrimaryWindow;
+use bevy::window::WindowResized;
+use bevy::window::WindowScaleFactorChanged;
+
+use crate::animation::PostTransformSystems;
+use crate::core::camera::PrimaryCamera;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<PixelPerfect>();
+}
+
+/// Render a camera at a fixed virtual resolution, upscaled by the largest integer factor
+/// that fits in the window. The remainder of the window is letterboxed.
+///
+/// Sprites are snapped to the virtual pixel grid of the [`PrimaryCamera`], and camera zoom is
+/// limited to whole numbers (see [`Self::snap_zoom`]).
+#[derive(Component, Reflect, Copy, Clone, Debug)]
+#[reflect(Component)]
+pub struct PixelPerfect {
+    pub resolution: UVec2,
+}
+
+impl Configure for PixelPerfect {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            (
+                apply_pixel_perfect.run_if(
+                    any_match_filter::<Changed<PixelPerfect>>
+                        .or(on_event::<WindowResized>)
+                        .or(on_event::<WindowScaleFactorChanged>),
+                ),
+                snap_to_pixel_grid.in_set(PostTransformSystems::Finish),
+            ),
+        );
+    }
+}
+
+impl PixelPerfect {
+    /// The integer-scaled viewport position and size within a window, in physical pixels.
+    pub fn viewport(&self, window_size: UVec2) -> (UVec2, UVec2) {
+        let resolution = self.resolution.max(UVec2::ONE);
+        let scale = (window_size / resolution).min_element().max(1);
+        let size = (resolution * scale).min(window_size).max(UVec2::ONE);
+        let pos = window_size.saturating_sub(size) / 2;
+        (pos, size)
+    }
+
+    /// Round a zoom to a whole number, so that each virtual pixel stays a whole number of
+    /// screen pixels.
+    pub fn snap_zoom(zoom: f32) -> f32 {
+        zoom.round().max(1.0)
+    }
+}
+
+fn apply_pixel_perfect(
+    mut pixel_query: Query<(&mut Camera, &PixelPerfect)>,
+    primary_window: Single<&Window, With<PrimaryWindow>>,
+) {
+    let window_size = primary_window.physical_size();
+    for (mut camera, pixel_perfect) in &mut pixel_query {
+        let (pos, size) = pixel_perfect.viewport(window_size);
+        camera.viewport = Some(Viewport {
+            physical_position: pos,
+            physical_size: size,
+            ..default()
+        });
+    }
+}
+
+fn snap_to_pixel_grid(
+    camera: Option<
+        Single<(&Projection, &mut GlobalTransform), (With<PrimaryCamera>, With<PixelPerfect>)>,
+    >,
+    mut sprite_query: Query<&mut GlobalTransform, (With<Sprite>, Without<PrimaryCamera>)>,
+) {
+    let (projection, mut camera_gt) = rq!(camera).into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+    // With a fixed scaling mode, one virtual pixel is `scale` world units wide.
+    let pixel_size = projection.scale;
+
+    // Snap the camera as well so that sprites land on whole pixels on screen.
+    snap(&mut camera_gt, pixel_size);
+    for mut gt in &mut sprite_query {
+        snap(&mut gt, pixel_size);
+    }
+}
+
+fn snap(gt: &mut GlobalTransform, pixel_size: f32) {
+    let mut affine = gt.affine();
+    let xy = (affine.translation.xy() / pixel_size).round() * pixel_size;
+    affine.translation = xy.extend(affine.translation.z).into();
+    *gt = affine.into();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport() {
+        let pixel_perfect = PixelPerfect {
+            resolution: UVec2::new(320, 180),
+        };
+        for (window_size, want) in [
+            (
+                UVec2::new(1280, 720),
+                (UVec2::new(0, 0), UVec2::new(1280, 720)),
+            ),
+            (
+                UVec2::new(1366, 768),
+                (UVec2::new(43, 24), UVec2::new(1280, 720)),
+            ),
+            (
+                UVec2::new(1920, 1200),
+                (UVec2::new(0, 60), UVec2::new(1920, 1080)),
+            ),
+            (
+                UVec2::new(200, 100),
+                (UVec2::new(0, 0), UVec2::new(200, 100)),
+            ),
+        ] {
+            assert_eq!(pixel_perfect.viewport(window_size), want, "{window_size}");
+        }
+    }
+
+    #[test]
+    fn test_snap_zoom() {
+        for (zoom, want) in [(0.25, 1.0), (1.0, 1.0), (1.4, 1.0), (1.6, 2.0), (3.0, 3.0)] {
+            assert_eq!(PixelPerfect::snap_zoom(zoom), want, "{zoom}");
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::animation::PostTransformSystems;
+use crate::animation::backup::Backup;
+use crate::core::accessibility::AccessibilitySettings;
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(CameraShake, AddTrauma)>();
+}
+
+/// Shake a camera based on its accumulated trauma.
+///
+/// The shake intensity is `trauma ^ exponent`, so small amounts of trauma barely shake.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+#[require(Backup<Transform>)]
+pub struct CameraShake {
+    /// The current trauma, from 0 to 1.
+    pub trauma: f32,
+    /// The trauma lost per second.
+    pub decay: f32,
+    pub exponent: f32,
+    /// The translation offset at full intensity, in pixels.
+    pub max_offset: Vec2,
+    /// The rotation offset at full intensity, in radians.
+    pub max_rotation: f32,
+    /// How quickly the noise changes, in cycles per second.
+    pub frequency: f32,
+    /// The time to sample noise at, which only advances while unpaused.
+    time: f32,
+}
+
+impl Configure for CameraShake {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            Update,
+            decay_camera_shake
+                .in_set(UpdateSystems::TickTimers)
+                .in_set(PausableSystems),
+        );
+        app.add_systems(
+            PostUpdate,
+            apply_camera_shake.in_set(PostTransformSystems::Blend),
+        );
+    }
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            exponent: 2.0,
+            max_offset: Vec2::splat(16.0),
+            max_rotation: 0.05,
+            frequency: 15.0,
+            time: 0.0,
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+
+    /// The current shake intensity, from 0 to 1.
+    pub fn intensity(&self) -> f32 {
+        self.trauma.powf(self.exponent)
+    }
+}
+
+fn decay_camera_shake(time: Res<Time>, mut shake_query: Query<&mut CameraShake>) {
+    let dt = time.delta_secs();
+    for mut shake in &mut shake_query {
+        shake.time += dt;
+        shake.trauma = (shake.trauma - shake.decay * dt).max(0.0);
+    }
+}
+
+fn apply_camera_shake(
+    accessibility_settings: Res<AccessibilitySettings>,
+    mut shake_query: Query<(&CameraShake, &mut Transform)>,
+) {
+    rq!(!accessibility_settings.reduced_motion);
+    for (shake, mut transform) in &mut shake_query {
+        let intensity = shake.intensity();
+        cq!(intensity > 0.0);
+
+        let t = shake.time * shake.frequency;
+        let offset = vec2(noise(0, t), noise(1, t)) * shake.max_offset * intensity;
+        transform.translation += offset.extend(0.0);
+        transform.rotate_z(noise(2, t) * shake.max_rotation * intensity);
+    }
+}
+
+/// An event that adds trauma to every [`CameraShake`].
+#[derive(Event, Reflect, Copy, Clone, Debug)]
+pub struct AddTrauma(pub f32);
+
+impl Configure for AddTrauma {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_event::<Self>();
+        app.add_systems(
+            Update,
+            add_trauma
+                .in_set(UpdateSystems::HandleEvents)
+                .in_set(PausableSystems),
+        );
+    }
+}
+
+fn add_trauma(mut trauma_events: EventReader<AddTrauma>, mut shake_query: Query<&mut CameraShake>) {
+    for event in trauma_events.read() {
+        for mut shake in &mut shake_query {
+            shake.add_trauma(event.0);
+        }
+    }
+}
+
+/// Smooth 1D gradient noise from -1 to 1.
+fn noise(seed: u32, x: f32) -> f32 {
+    let i = x.floor();
+    let f = x - i;
+    let a = gradient(seed, i as i32) * f;
+    let b = gradient(seed, i as i32 + 1) * (f - 1.0);
+    let t = f * f * (3.0 - 2.0 * f);
+    // 1D gradient noise stays within -0.5 to 0.5.
+    2.0 * a.lerp(b, t)
+}
+
+/// A pseudo-random gradient from -1 to 1 at an integer lattice point.
+fn gradient(seed: u32, i: i32) -> f32 {
+    let mut h = (i as u32).wrapping_mul(0x9e37_79b1) ^ seed.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2c1b_3c6d);
+    h ^= h >> 12;
+    h as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noise() {
+        for i in 0..1000 {
+            let x = i as f32 * 0.037;
+            let y = noise(0, x);
+            assert!((-1.0..=1.0).contains(&y), "noise at {x}: {y}");
+            // Noise is zero at lattice points and continuous between them.
+            assert!((noise(0, x + 0.001) - y).abs() < 0.01, "noise at {x}");
+        }
+        assert_eq!(noise(0, 3.0), 0.0);
+        assert_ne!(noise(0, 0.5), noise(1, 0.5));
+    }
+}
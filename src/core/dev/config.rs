@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::util::config::SaveConfigs;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, save_configs.run_if(input_just_pressed(SAVE_KEY)));
+}
+
+const SAVE_KEY: KeyCode = KeyCode::F5;
+
+fn save_configs(mut commands: Commands) {
+    commands.trigger(SaveConfigs);
+}
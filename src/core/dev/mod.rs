@@ -1,5 +1,7 @@
 //! Dev tools for dev builds.
 
+#[cfg(feature = "native_dev")]
+mod config;
 mod diagnostics;
 #[cfg(feature = "native_dev")]
 mod editor;
@@ -15,6 +17,8 @@ pub(super) fn plugin(app: &mut App) {
     app.configure::<ConfigHandle<DevConfig>>();
 
     app.add_plugins((
+        #[cfg(feature = "native_dev")]
+        config::plugin,
         diagnostics::plugin,
         #[cfg(feature = "native_dev")]
         editor::plugin,
@@ -32,17 +36,25 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(Update, debug_end);
 }
 
-#[derive(Asset, Reflect, Serialize, Deserialize)]
+/// Dev tool settings. Every field is optional.
+#[derive(Asset, Reflect, Serialize, Deserialize, Clone)]
+#[reflect(Default)]
 #[serde(deny_unknown_fields, default)]
-struct DevConfig {
+pub struct DevConfig {
     // Diagnostics:
+    /// Log FPS and frame time.
     pub log_frame_time: bool,
+    /// Log CPU and memory usage.
     pub log_system_information: bool,
+    /// Log the number of entities.
     pub log_entity_count: bool,
 
     // State:
+    /// Log state transitions.
     pub log_state_flush: bool,
+    /// The minimum number of seconds to show the loading menu for.
     pub extend_loading_menu: f32,
+    /// The screen to start on instead of the splash screen.
     pub initial_screen: Option<Screen>,
 }
 
@@ -63,9 +75,20 @@ impl Default for DevConfig {
 impl Config for DevConfig {
     const FILE: &'static str = ".dev.ron";
 
-    fn on_load(&self, world: &mut World) {
+    fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        if !(self.extend_loading_menu.is_finite() && self.extend_loading_menu >= 0.0) {
+            return Err(vec![ConfigError::new(
+                "extend_loading_menu",
+                "must be non-negative",
+            )]);
+        }
+
+        Ok(())
+    }
+
+    fn on_load(&self, world: &mut World, changed: &ConfigChanged<Self>) {
         diagnostics::on_load(self, world);
-        state::on_load(self, world);
+        state::on_load(self, world, changed);
     }
 }
 
@@ -1,5 +1,6 @@
 //! Foundational features and cross-cutting concerns.
 
+pub mod accessibility;
 pub mod asset;
 pub mod audio;
 pub mod camera;
@@ -8,6 +9,8 @@ pub mod dev;
 pub mod pause;
 pub mod physics;
 pub mod state;
+#[cfg(test)]
+pub mod test;
 pub mod window;
 
 use bevy::audio::AudioPlugin;
@@ -18,20 +21,24 @@ pub(super) fn plugin(app: &mut App) {
     app.configure::<UpdateSystems>();
 
     // Add Bevy plugins.
-    app.add_plugins(
-        DefaultPlugins
-            .build()
-            .set(ImagePlugin::default_nearest())
-            .replace::<AudioPlugin>(audio::plugin)
-            .replace::<WindowPlugin>(window::plugin)
-            // `window::plugin` requires the following plugins:
-            .disable::<AssetPlugin>()
-            .add_before::<WindowPlugin>(asset::plugin)
-            .add_before::<WindowPlugin>(state::plugin),
-    );
+    let plugins = DefaultPlugins
+        .build()
+        .set(ImagePlugin::default_nearest())
+        .replace::<AudioPlugin>(audio::plugin)
+        .replace::<WindowPlugin>(window::plugin)
+        // `window::plugin` requires the following plugins:
+        .disable::<AssetPlugin>()
+        .add_before::<WindowPlugin>(asset::plugin)
+        .add_before::<WindowPlugin>(state::plugin);
+
+    // Swap out windowing and rendering in headless tests.
+    #[cfg(test)]
+    let plugins = test::headless(plugins);
+    app.add_plugins(plugins);
 
     // Add other core plugins.
     app.add_plugins((
+        accessibility::plugin,
         camera::plugin,
         #[cfg(feature = "dev")]
         dev::plugin,
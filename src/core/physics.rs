@@ -8,7 +8,7 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Pause.on_edge(unpause_physics, pause_physics));
 }
 
-const PIXELS_PER_METER: f32 = 16.0;
+pub const PIXELS_PER_METER: f32 = 16.0;
 
 fn unpause_physics(mut physics_time: ResMut<Time<Physics>>) {
     physics_time.unpause();
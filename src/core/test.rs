@@ -0,0 +1,176 @@
+//! A headless harness for testing the full app without a window or GPU.
+
+use std::time::Instant;
+
+use bevy::app::PluginGroupBuilder;
+use bevy::app::PluginsState;
+use bevy::ecs::system::RunSystemOnce as _;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::Key;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::keyboard::NativeKey;
+use bevy::picking::backend::HitData;
+use bevy::picking::pointer::Location;
+use bevy::picking::pointer::PointerId;
+use bevy::render::RenderPlugin;
+use bevy::render::camera::NormalizedRenderTarget;
+use bevy::render::settings::WgpuSettings;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::PrimaryWindow;
+use bevy::window::WindowRef;
+use bevy::winit::WinitPlugin;
+
+use crate::menu::Menu;
+use crate::prelude::*;
+use crate::screen::Screen;
+
+/// Swap out windowing and rendering for headless equivalents.
+pub(super) fn headless(plugins: PluginGroupBuilder) -> PluginGroupBuilder {
+    plugins.disable::<WinitPlugin>().set(RenderPlugin {
+        render_creation: WgpuSettings {
+            backends: None,
+            ..default()
+        }
+        .into(),
+        ..default()
+    })
+}
+
+/// The fixed time step between frames.
+const FRAME_SECS: f64 = 1.0 / 60.0;
+
+/// The maximum real time to wait for a condition, including asset loading.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A headless app running [`crate::plugin`] with a fixed time step per frame.
+pub struct TestApp(pub App);
+
+impl Default for TestApp {
+    fn default() -> Self {
+        let mut app = App::new();
+        app.add_plugins(crate::plugin);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            FRAME_SECS,
+        )));
+
+        // Do what `App::run` would do before the first update.
+        while app.plugins_state() == PluginsState::Adding {
+            bevy::tasks::tick_global_task_pools_on_main_thread();
+        }
+        app.finish();
+        app.cleanup();
+
+        Self(app)
+    }
+}
+
+impl TestApp {
+    /// Step a single frame.
+    pub fn step(&mut self) {
+        self.0.update();
+    }
+
+    /// Step the given number of frames.
+    #[allow(dead_code)]
+    pub fn step_n(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Step frames until the condition is true.
+    ///
+    /// Panics if the condition is not met within [`TIMEOUT`].
+    pub fn step_until(&mut self, description: &str, condition: impl Fn(&Self) -> bool) {
+        let start = Instant::now();
+        while !condition(self) {
+            assert!(
+                start.elapsed() < TIMEOUT,
+                "Timed out waiting for {description}: screen = {:?}, menu = {:?}, paused = {}",
+                self.screen(),
+                self.menu(),
+                self.is_paused(),
+            );
+            self.step();
+        }
+    }
+
+    /// Run a one-shot system, for example to push a menu.
+    #[allow(dead_code)]
+    pub fn run<M>(&mut self, system: impl IntoSystem<(), (), M>) {
+        self.0.world_mut().run_system_once(system).unwrap();
+    }
+
+    /// Press and release a key over the next frame.
+    pub fn tap_key(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Pressed);
+        self.step();
+        self.send_key(key_code, ButtonState::Released);
+        self.step();
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) {
+        let window = self.primary_window();
+        self.0.world_mut().send_event(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state,
+            text: None,
+            repeat: false,
+            window,
+        });
+    }
+
+    /// Click the button with the given text, as if with the mouse.
+    ///
+    /// Panics if there is no such button.
+    pub fn click(&mut self, text: &str) {
+        let window = self.primary_window();
+        let world = self.0.world_mut();
+        let name = format!("Button(\"{text}\")");
+        let button = world
+            .query_filtered::<(Entity, &Name), With<Button>>()
+            .iter(world)
+            .find_map(|(entity, x)| (x.as_str() == name).then_some(entity))
+            .unwrap_or_else(|| panic!("No button to click: {name}"));
+        let location = Location {
+            target: NormalizedRenderTarget::Window(
+                WindowRef::Primary.normalize(Some(window)).unwrap(),
+            ),
+            position: Vec2::ZERO,
+        };
+        world.trigger_targets(
+            Pointer::new(
+                PointerId::Mouse,
+                location,
+                button,
+                Click {
+                    button: PointerButton::Primary,
+                    hit: HitData::new(Entity::PLACEHOLDER, 0.0, None, None),
+                    duration: Duration::ZERO,
+                },
+            ),
+            button,
+        );
+    }
+
+    fn primary_window(&mut self) -> Entity {
+        let world = self.0.world_mut();
+        world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(world)
+            .unwrap()
+    }
+
+    pub fn screen(&self) -> Option<Screen> {
+        self.0.world().get_resource::<Screen>().copied()
+    }
+
+    pub fn menu(&self) -> Option<Menu> {
+        self.0.world().get_resource::<Menu>().copied()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.0.world().contains_resource::<Pause>()
+    }
+}
@@ -10,6 +10,9 @@ mod theme;
 mod util;
 
 use crate::prelude::*;
+// Expose config reflection for the debug binary.
+pub use crate::util::config::ConfigFormat;
+pub use crate::util::config::ReflectConfig;
 
 pub fn plugin(app: &mut App) {
     // Add core plugins.
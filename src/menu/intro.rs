@@ -2,7 +2,7 @@ use crate::menu::Menu;
 use crate::menu::MenuRootUi;
 use crate::prelude::*;
 use crate::screen::Screen;
-use crate::screen::fade::fade_out;
+use crate::screen::transition::transition_out;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Intro.on_enter(spawn_intro_menu));
@@ -43,7 +43,7 @@ fn start_game(
 ) {
     let Progress { done, total } = progress.get_global_combined_progress();
     if done >= total {
-        commands.spawn(fade_out(Screen::Gameplay));
+        commands.spawn(transition_out(Screen::Gameplay));
     } else {
         menu.push(Menu::Loading);
     }
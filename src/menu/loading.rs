@@ -2,7 +2,7 @@ use crate::menu::Menu;
 use crate::menu::MenuRootUi;
 use crate::prelude::*;
 use crate::screen::Screen;
-use crate::screen::fade::fade_out;
+use crate::screen::transition::transition_out;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Loading.on_enter(spawn_loading_menu));
@@ -33,7 +33,7 @@ fn update_loading(
 
     // Continue to the next screen when ready.
     if done == total {
-        commands.spawn(fade_out(Screen::Gameplay));
+        commands.spawn(transition_out(Screen::Gameplay));
     }
 
     info!("[Frame {}] Loading: {done} / {total}", frame.0);
@@ -32,7 +32,7 @@ fn spawn_menu_root_ui(mut commands: Commands) {
     ));
 }
 
-#[derive(State, Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(State, Reflect, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[state(before(Pause), next(NextStateStack<Self>), react, log_flush)]
 #[reflect(Resource)]
 pub enum Menu {
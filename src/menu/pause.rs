@@ -2,7 +2,7 @@ use crate::menu::Menu;
 use crate::menu::MenuRootUi;
 use crate::prelude::*;
 use crate::screen::Screen;
-use crate::screen::fade::fade_out;
+use crate::screen::transition::transition_out;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Pause.on_enter(spawn_pause_menu));
@@ -31,9 +31,9 @@ fn close_menu(_: Trigger<Pointer<Click>>, mut menu: NextMut<Menu>) {
 }
 
 fn restart_game(_: Trigger<Pointer<Click>>, mut commands: Commands) {
-    commands.spawn(fade_out(Screen::Gameplay));
+    commands.spawn(transition_out(Screen::Gameplay));
 }
 
 fn quit_to_title(_: Trigger<Pointer<Click>>, mut commands: Commands) {
-    commands.spawn(fade_out(Screen::Title));
+    commands.spawn(transition_out(Screen::Title));
 }
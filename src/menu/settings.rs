@@ -1,6 +1,9 @@
 use bevy_simple_prefs::Prefs;
 use bevy_simple_prefs::PrefsPlugin;
 
+use crate::core::accessibility::AccessibilitySettings;
+use crate::core::audio::AudioCategories;
+use crate::core::audio::AudioCategory;
 use crate::core::audio::AudioSettings;
 use crate::menu::Menu;
 use crate::menu::MenuRootUi;
@@ -9,22 +12,20 @@ use crate::prelude::*;
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(StateFlush, Menu::Settings.on_enter(spawn_settings_menu));
 
-    app.configure::<(
-        Settings,
-        MasterVolumeSelector,
-        MusicVolumeSelector,
-        SfxVolumeSelector,
-        UiVolumeSelector,
-    )>();
+    app.configure::<(Settings, VolumeSelector, MuteToggle, ReducedMotionToggle)>();
 }
 
-fn spawn_settings_menu(mut commands: Commands, menu_root_ui: Single<Entity, With<MenuRootUi>>) {
+fn spawn_settings_menu(
+    mut commands: Commands,
+    menu_root_ui: Single<Entity, With<MenuRootUi>>,
+    categories: Res<AudioCategories>,
+) {
     commands
         .entity(*menu_root_ui)
         .with_child(widget::root(children![widget::full_popup(children![
             widget::center(children![
                 widget::header(children![widget::h1("[b]Settings")]),
-                grid(),
+                grid(&categories),
                 widget::footer(children![widget::row_of_buttons(children![
                     widget::wide_button("Back", go_back),
                 ])]),
@@ -36,7 +37,8 @@ fn go_back(_: Trigger<Pointer<Click>>, mut menu: ResMut<NextStateStack<Menu>>) {
     menu.pop();
 }
 
-fn grid() -> impl Bundle {
+fn grid(categories: &AudioCategories) -> impl Bundle {
+    let categories = categories.0.clone();
     (
         Name::new("Grid"),
         Node {
@@ -46,218 +48,181 @@ fn grid() -> impl Bundle {
             grid_template_columns: vec![
                 RepeatedGridTrack::flex(1, 1.0),
                 RepeatedGridTrack::flex(1, 1.2),
+                RepeatedGridTrack::auto(1),
             ],
             ..default()
         },
-        GridAlignment::columns([JustifySelf::End, JustifySelf::Start]),
-        children![
-            widget::label("Master volume"),
-            widget::selector(MasterVolumeSelector, master_volume_down, master_volume_up),
-            widget::label("Music volume"),
-            widget::selector(MusicVolumeSelector, music_volume_down, music_volume_up),
-            widget::label("SFX volume"),
-            widget::selector(SfxVolumeSelector, sfx_volume_down, sfx_volume_up),
-            widget::label("UI volume"),
-            widget::selector(UiVolumeSelector, ui_volume_down, ui_volume_up),
-        ],
+        GridAlignment::columns([JustifySelf::End, JustifySelf::Start, JustifySelf::Start]),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            for info in categories {
+                parent.spawn(widget::label(format!("{} volume", info.label)));
+                parent.spawn(widget::selector(
+                    VolumeSelector(info.category.clone()),
+                    volume_down,
+                    volume_up,
+                ));
+                parent.spawn((
+                    widget::button_base(Vw(14.0), Vw(4.0), Vw(3.0), "Mute", toggle_mute),
+                    MuteToggle(info.category),
+                ));
+            }
+            parent.spawn(widget::label("Reduced motion"));
+            parent.spawn((
+                widget::button_base(Vw(14.0), Vw(4.0), Vw(3.0), "Off", toggle_reduced_motion),
+                ReducedMotionToggle,
+            ));
+            parent.spawn(Node::default());
+        })),
     )
 }
 
+/// A selector for the volume of an [`AudioCategory`].
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
-struct MasterVolumeSelector;
+struct VolumeSelector(AudioCategory);
 
-impl Configure for MasterVolumeSelector {
+impl Configure for VolumeSelector {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
         app.add_systems(
             Update,
-            Menu::Settings.on_update(update_master_volume_selector.in_set(UpdateSystems::Update)),
+            Menu::Settings.on_update(update_volume_selectors.in_set(UpdateSystems::Update)),
         );
     }
 }
 
-fn update_master_volume_selector(
+fn update_volume_selectors(
     audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<MasterVolumeSelector>>,
+    selector_query: Query<(Entity, &VolumeSelector)>,
     children_query: Query<&Children>,
     mut text_query: Query<&mut RichText>,
     mut disabled_query: Query<&mut InteractionDisabled>,
 ) {
-    for entity in &selector_query {
+    for (entity, selector) in &selector_query {
+        let volume = audio_settings.volume(&selector.0);
         let children = c!(children_query.get(entity))
             .into_iter()
             .collect::<Vec<_>>();
 
         let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.master_volume <= f32::EPSILON;
+        c!(disabled_query.get_mut(left)).0 = volume <= f32::EPSILON;
 
         let mid = **c!(children.get(1));
         let mid_children = c!(children_query.get(mid));
         let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.master_volume * 100.0));
+        c!(text_query.get_mut(label)).sections = parse_rich(format!("{:.0}%", volume * 100.0));
 
         let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.master_volume >= 1.0 - f32::EPSILON;
+        c!(disabled_query.get_mut(right)).0 = volume >= 1.0 - f32::EPSILON;
     }
 }
 
-fn master_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.master_volume = (audio_settings.master_volume - 0.1).max(0.0);
-}
-
-fn master_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.master_volume = (audio_settings.master_volume + 0.1).min(1.0);
-}
-
-#[derive(Component, Reflect, Debug)]
-#[reflect(Component)]
-struct MusicVolumeSelector;
-
-impl Configure for MusicVolumeSelector {
-    fn configure(app: &mut App) {
-        app.register_type::<Self>();
-        app.add_systems(
-            Update,
-            Menu::Settings.on_update(update_music_volume_selector.in_set(UpdateSystems::Update)),
-        );
-    }
-}
-
-fn update_music_volume_selector(
-    audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<MusicVolumeSelector>>,
-    children_query: Query<&Children>,
-    mut text_query: Query<&mut RichText>,
-    mut disabled_query: Query<&mut InteractionDisabled>,
+fn volume_down(
+    trigger: Trigger<Pointer<Click>>,
+    parent_query: Query<&ChildOf>,
+    selector_query: Query<&VolumeSelector>,
+    mut audio_settings: ResMut<AudioSettings>,
 ) {
-    for entity in &selector_query {
-        let children = c!(children_query.get(entity))
-            .into_iter()
-            .collect::<Vec<_>>();
-
-        let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.music_volume <= f32::EPSILON;
-
-        let mid = **c!(children.get(1));
-        let mid_children = c!(children_query.get(mid));
-        let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.music_volume * 100.0));
-
-        let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.music_volume >= 1.0 - f32::EPSILON;
-    }
-}
-
-fn music_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.music_volume = (audio_settings.music_volume - 0.1).max(0.0);
+    let parent = r!(parent_query.get(trigger.target())).parent();
+    let category = &r!(selector_query.get(parent)).0;
+    let volume = audio_settings.volume(category);
+    audio_settings.set_volume(category, volume - 0.1);
 }
 
-fn music_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.music_volume = (audio_settings.music_volume + 0.1).min(1.0);
+fn volume_up(
+    trigger: Trigger<Pointer<Click>>,
+    parent_query: Query<&ChildOf>,
+    selector_query: Query<&VolumeSelector>,
+    mut audio_settings: ResMut<AudioSettings>,
+) {
+    let parent = r!(parent_query.get(trigger.target())).parent();
+    let category = &r!(selector_query.get(parent)).0;
+    let volume = audio_settings.volume(category);
+    audio_settings.set_volume(category, volume + 0.1);
 }
 
+/// A button that toggles whether an [`AudioCategory`] is muted.
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
-struct SfxVolumeSelector;
+struct MuteToggle(AudioCategory);
 
-impl Configure for SfxVolumeSelector {
+impl Configure for MuteToggle {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
         app.add_systems(
             Update,
-            Menu::Settings.on_update(update_sfx_volume_selector.in_set(UpdateSystems::Update)),
+            Menu::Settings.on_update(update_mute_toggles.in_set(UpdateSystems::Update)),
         );
     }
 }
 
-fn update_sfx_volume_selector(
+fn update_mute_toggles(
     audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<SfxVolumeSelector>>,
-    children_query: Query<&Children>,
+    toggle_query: Query<(&MuteToggle, &Children)>,
     mut text_query: Query<&mut RichText>,
-    mut disabled_query: Query<&mut InteractionDisabled>,
 ) {
-    for entity in &selector_query {
-        let children = c!(children_query.get(entity))
-            .into_iter()
-            .collect::<Vec<_>>();
-
-        let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.sfx_volume <= f32::EPSILON;
-
-        let mid = **c!(children.get(1));
-        let mid_children = c!(children_query.get(mid));
-        let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.sfx_volume * 100.0));
-
-        let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.sfx_volume >= 1.0 - f32::EPSILON;
+    for (toggle, children) in &toggle_query {
+        let label = *c!(children.first());
+        let text = if audio_settings.is_muted(&toggle.0) {
+            "Unmute"
+        } else {
+            "Mute"
+        };
+        c!(text_query.get_mut(label)).sections = parse_rich(text);
     }
 }
 
-fn sfx_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.sfx_volume = (audio_settings.sfx_volume - 0.1).max(0.0);
-}
-
-fn sfx_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.sfx_volume = (audio_settings.sfx_volume + 0.1).min(1.0);
+fn toggle_mute(
+    trigger: Trigger<Pointer<Click>>,
+    toggle_query: Query<&MuteToggle>,
+    mut audio_settings: ResMut<AudioSettings>,
+) {
+    let category = &r!(toggle_query.get(trigger.target())).0;
+    let muted = audio_settings.is_muted(category);
+    audio_settings.set_muted(category, !muted);
 }
 
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
-struct UiVolumeSelector;
+struct ReducedMotionToggle;
 
-impl Configure for UiVolumeSelector {
+impl Configure for ReducedMotionToggle {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
         app.add_systems(
             Update,
-            Menu::Settings.on_update(update_ui_volume_selector.in_set(UpdateSystems::Update)),
+            Menu::Settings.on_update(update_reduced_motion_toggle.in_set(UpdateSystems::Update)),
         );
     }
 }
 
-fn update_ui_volume_selector(
-    audio_settings: Res<AudioSettings>,
-    selector_query: Query<Entity, With<UiVolumeSelector>>,
-    children_query: Query<&Children>,
+fn update_reduced_motion_toggle(
+    accessibility_settings: Res<AccessibilitySettings>,
+    toggle_query: Query<&Children, With<ReducedMotionToggle>>,
     mut text_query: Query<&mut RichText>,
-    mut disabled_query: Query<&mut InteractionDisabled>,
 ) {
-    for entity in &selector_query {
-        let children = c!(children_query.get(entity))
-            .into_iter()
-            .collect::<Vec<_>>();
-
-        let left = **c!(children.first());
-        c!(disabled_query.get_mut(left)).0 = audio_settings.ui_volume <= f32::EPSILON;
-
-        let mid = **c!(children.get(1));
-        let mid_children = c!(children_query.get(mid));
-        let label = *c!(mid_children.first());
-        c!(text_query.get_mut(label)).sections =
-            parse_rich(format!("{:.0}%", audio_settings.ui_volume * 100.0));
-
-        let right = **c!(children.get(2));
-        c!(disabled_query.get_mut(right)).0 = audio_settings.ui_volume >= 1.0 - f32::EPSILON;
+    for children in &toggle_query {
+        let label = *c!(children.first());
+        let text = if accessibility_settings.reduced_motion {
+            "On"
+        } else {
+            "Off"
+        };
+        c!(text_query.get_mut(label)).sections = parse_rich(text);
     }
 }
 
-fn ui_volume_down(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.ui_volume = (audio_settings.ui_volume - 0.1).max(0.0);
-}
-
-fn ui_volume_up(_: Trigger<Pointer<Click>>, mut audio_settings: ResMut<AudioSettings>) {
-    audio_settings.ui_volume = (audio_settings.ui_volume + 0.1).min(1.0);
+fn toggle_reduced_motion(
+    _: Trigger<Pointer<Click>>,
+    mut accessibility_settings: ResMut<AccessibilitySettings>,
+) {
+    accessibility_settings.reduced_motion ^= true;
 }
 
 #[derive(Prefs, Reflect, Default)]
 struct Settings {
     pub audio_settings: AudioSettings,
+    pub accessibility_settings: AccessibilitySettings,
 }
 
 impl Configure for Settings {
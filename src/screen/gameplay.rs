@@ -1,5 +1,5 @@
-use crate::core::audio::AudioSettings;
-use crate::core::audio::music_audio;
+use crate::core::audio::music::MusicDirector;
+use crate::core::audio::music::MusicTrack;
 use crate::menu::Menu;
 use crate::prelude::*;
 use crate::screen::Screen;
@@ -20,24 +20,26 @@ pub(super) fn plugin(app: &mut App) {
         ),
     );
 
+    app.add_systems(Startup, set_gameplay_music);
+
     app.configure::<(GameplayAssets, GameplayAction)>();
 }
 
 fn spawn_gameplay_screen(
     mut commands: Commands,
     screen_root_ui: Single<Entity, With<ScreenRootUi>>,
-    audio_settings: Res<AudioSettings>,
-    assets: Res<GameplayAssets>,
 ) {
     commands
         .entity(*screen_root_ui)
         .with_child(widget::center(children![widget::label(
             "Gameplay goes here. Press P to pause!",
         )]));
-    commands.spawn((
-        music_audio(&audio_settings, assets.music.clone()),
-        DespawnOnExitState::<Screen>::Recursive,
-    ));
+}
+
+fn set_gameplay_music(mut director: ResMut<MusicDirector>, assets: Res<GameplayAssets>) {
+    director
+        .screen_tracks
+        .insert(Screen::Gameplay, MusicTrack::new(assets.music.clone()));
 }
 
 fn spawn_menu_overlay(mut commands: Commands) {
@@ -1,9 +1,11 @@
-pub mod fade;
 mod gameplay;
 mod splash;
 mod title;
+pub mod transition;
 
+use crate::animation::backup::Backup;
 use crate::core::camera::PrimaryCamera;
+use crate::core::camera::motion::CameraMoves;
 use crate::menu::Menu;
 use crate::prelude::*;
 use crate::theme::widget::LoadingBarFill;
@@ -11,11 +13,12 @@ use crate::theme::widget::LoadingBarFill;
 pub fn plugin(app: &mut App) {
     app.configure::<(ScreenRootUi, Screen, ScreenTime)>();
 
-    app.add_plugins(fade::plugin);
+    app.add_plugins(transition::plugin);
 }
 
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
+#[require(Backup<Transform>)]
 struct ScreenRootUi;
 
 impl Configure for ScreenRootUi {
@@ -63,9 +66,9 @@ impl Configure for Screen {
     }
 }
 
-fn reset_screen_camera(mut camera_query: Query<&mut Transform, With<PrimaryCamera>>) {
-    for mut transform in &mut camera_query {
-        *transform = default();
+fn reset_screen_camera(mut camera_query: Query<&mut CameraMoves, With<PrimaryCamera>>) {
+    for mut moves in &mut camera_query {
+        moves.push_reset();
     }
 }
 
@@ -95,3 +98,47 @@ fn reset_screen_time(mut screen_time: ResMut<ScreenTime>) {
 fn tick_screen_time(time: Res<Time>, mut screen_time: ResMut<ScreenTime>) {
     screen_time.0 += time.delta();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test::TestApp;
+
+    #[test]
+    fn test_screen_flow() {
+        let mut app = TestApp::default();
+        app.step();
+        assert_eq!(app.screen(), Some(Screen::Splash));
+
+        app.step_until("title screen", |app| {
+            app.screen() == Some(Screen::Title) && app.menu() == Some(Menu::Main)
+        });
+        assert!(app.is_paused());
+
+        app.click("Play");
+        app.step_until("intro menu", |app| app.menu() == Some(Menu::Intro));
+        app.click("Start");
+        app.step_until("gameplay screen", |app| {
+            app.screen() == Some(Screen::Gameplay) && app.menu().is_none()
+        });
+        assert!(!app.is_paused());
+
+        app.tap_key(KeyCode::KeyP);
+        app.step_until("pause menu", |app| app.menu() == Some(Menu::Pause));
+        assert!(app.is_paused());
+
+        app.click("Restart");
+        app.step_until("restarted gameplay screen", |app| {
+            app.screen() == Some(Screen::Gameplay) && app.menu().is_none()
+        });
+        assert!(!app.is_paused());
+
+        app.tap_key(KeyCode::KeyP);
+        app.step_until("pause menu", |app| app.menu() == Some(Menu::Pause));
+
+        app.click("Quit to title");
+        app.step_until("title screen", |app| {
+            app.screen() == Some(Screen::Title) && app.menu() == Some(Menu::Main)
+        });
+    }
+}
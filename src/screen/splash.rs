@@ -2,26 +2,44 @@ use bevy::diagnostic::FrameCount;
 use bevy::image::ImageLoaderSettings;
 use bevy::image::ImageSampler;
 
+use crate::core::audio::sound::AudioConfig;
+use crate::core::camera::CameraConfig;
+#[cfg(feature = "dev")]
+use crate::core::dev::DevConfig;
 use crate::prelude::*;
 use crate::screen::Screen;
 use crate::screen::ScreenRootUi;
 use crate::screen::ScreenTime;
-use crate::screen::fade::FADE_IN_SECS;
-use crate::screen::fade::FadeOut;
-use crate::screen::fade::fade_out;
 use crate::screen::title::TitleAssets;
-use crate::theme::ThemeAssets;
+use crate::screen::transition::ScreenTransition;
+use crate::screen::transition::TransitionConfig;
+use crate::screen::transition::transition_out;
+use crate::theme::color::ThemeConfig;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_loading_state(
-        LoadingState::new(Screen::Splash.bevy())
-            .load_collection::<TitleAssets>()
-            .load_collection::<ThemeAssets>(),
+        LoadingState::new(Screen::Splash.bevy()).load_collection::<TitleAssets>(),
     );
     app.add_systems(StateFlush, Screen::Splash.on_enter(spawn_splash_screen));
     app.add_systems(Update, Screen::Splash.on_update(update_splash));
+
+    track_config_progress::<AudioConfig>(app);
+    track_config_progress::<CameraConfig>(app);
+    #[cfg(feature = "dev")]
+    track_config_progress::<DevConfig>(app);
+    track_config_progress::<ThemeConfig>(app);
+    track_config_progress::<TransitionConfig>(app);
+}
+
+/// Wait for a [`Config`] and its assets to load before leaving the splash screen.
+fn track_config_progress<C: Config>(app: &mut App) {
+    app.add_systems(
+        Update,
+        Screen::Splash.on_update(C::progress.track_progress::<BevyState<Screen>>()),
+    );
 }
 
+/// The minimum time to show the splash screen after its enter transition.
 const SPLASH_SCREEN_MIN_SECS: f32 = 0.8;
 
 fn spawn_splash_screen(
@@ -51,7 +69,8 @@ fn update_splash(
     screen_time: Res<ScreenTime>,
     progress: Res<ProgressTracker<BevyState<Screen>>>,
     frame: Res<FrameCount>,
-    fade_out_query: Query<(), With<FadeOut>>,
+    transition_query: Query<&ScreenTransition>,
+    mut enter_secs: Local<f32>,
     mut last_done: Local<u32>,
 ) {
     let Progress { done, total } = progress.get_global_combined_progress();
@@ -60,11 +79,18 @@ fn update_splash(
     }
     *last_done = done;
 
+    // The enter transition can't use the config if it wasn't loaded yet, so check its duration.
+    for transition in &transition_query {
+        if !transition.is_exit() {
+            *enter_secs = transition.duration();
+        }
+    }
+
     // Continue to the next screen when ready.
     if done == total
-        && screen_time.0.as_secs_f32() >= FADE_IN_SECS + SPLASH_SCREEN_MIN_SECS
-        && fade_out_query.is_empty()
+        && screen_time.0.as_secs_f32() >= *enter_secs + SPLASH_SCREEN_MIN_SECS
+        && transition_query.is_empty()
     {
-        commands.spawn(fade_out(Screen::Title));
+        commands.spawn(transition_out(Screen::Title));
     }
 }
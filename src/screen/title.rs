@@ -1,5 +1,5 @@
-use crate::core::audio::AudioSettings;
-use crate::core::audio::music_audio;
+use crate::core::audio::music::MusicDirector;
+use crate::core::audio::music::MusicTrack;
 use crate::menu::Menu;
 use crate::prelude::*;
 use crate::screen::Screen;
@@ -11,24 +11,18 @@ pub(super) fn plugin(app: &mut App) {
     );
     app.add_systems(
         StateFlush,
-        Screen::Title.on_enter((
-            (Menu::Main.enter(), Menu::acquire).chain(),
-            spawn_title_screen,
-        )),
+        Screen::Title.on_enter((Menu::Main.enter(), Menu::acquire).chain()),
     );
+    app.add_systems(Startup, set_title_music);
 
     app.configure::<TitleAssets>();
 }
 
-fn spawn_title_screen(
-    mut commands: Commands,
-    audio_settings: Res<AudioSettings>,
-    assets: Res<TitleAssets>,
-) {
-    commands.spawn((
-        music_audio(&audio_settings, assets.music.clone()),
-        DespawnOnExitState::<Screen>::Recursive,
-    ));
+fn set_title_music(mut director: ResMut<MusicDirector>, assets: Res<TitleAssets>) {
+    director.screen_tracks.insert(
+        Screen::Title,
+        MusicTrack::new(assets.music.clone()).resume(),
+    );
 }
 
 #[derive(AssetCollection, Resource, Reflect, Default)]
@@ -0,0 +1,519 @@
+use bevy::math::curve::Curve;
+use bevy::math::curve::EaseFunction;
+use bevy::math::curve::EasingCurve;
+use bevy::ui::UiSystem;
+
+use crate::animation::PostColorSystems;
+use crate::animation::PostTransformSystems;
+use crate::animation::backup::Backup;
+use crate::prelude::*;
+use crate::screen::Screen;
+use crate::screen::ScreenRootUi;
+
+pub(super) fn plugin(app: &mut App) {
+    app.configure::<(
+        ConfigHandle<TransitionConfig>,
+        ScreenTransition,
+        TransitionPanel,
+        TransitionIris,
+        DissolveCell,
+    )>();
+}
+
+/// The default screen transitions.
+#[derive(Asset, Reflect, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionConfig {
+    /// The transition for entering a screen (see [`transition_in`]).
+    enter: Transition,
+    /// The transition for exiting a screen (see [`transition_out`]).
+    exit: Transition,
+}
+
+impl Config for TransitionConfig {
+    const FILE: &'static str = "transition.ron";
+
+    fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = vec![];
+
+        for (field, transition) in [("enter", &self.enter), ("exit", &self.exit)] {
+            if !(transition.duration > 0.0 && transition.duration.is_finite()) {
+                errors.push(ConfigError::new(
+                    format!("{field}.duration"),
+                    "must be positive",
+                ));
+            }
+            if matches!(transition.style, TransitionStyle::Dissolve(x, y) if x == 0 || y == 0) {
+                errors.push(ConfigError::new(
+                    format!("{field}.style"),
+                    "must have at least one column and row",
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// A screen transition animation.
+///
+/// Exit transitions cover the screen, and enter transitions uncover it.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Transition {
+    pub style: TransitionStyle,
+    /// The duration of the transition, in seconds.
+    pub duration: f32,
+    pub ease: EaseFunction,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            style: TransitionStyle::Fade,
+            duration: 0.3,
+            ease: EaseFunction::Linear,
+        }
+    }
+}
+
+impl Transition {
+    #[allow(dead_code)]
+    pub fn fade() -> Self {
+        Self::default()
+    }
+
+    #[allow(dead_code)]
+    pub fn wipe(dir: TransitionDir) -> Self {
+        Self {
+            style: TransitionStyle::Wipe(dir),
+            ..default()
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn iris() -> Self {
+        Self {
+            style: TransitionStyle::Iris,
+            ..default()
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn dissolve(columns: u16, rows: u16) -> Self {
+        Self {
+            style: TransitionStyle::Dissolve(columns, rows),
+            ..default()
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn slide(dir: TransitionDir) -> Self {
+        Self {
+            style: TransitionStyle::Slide(dir),
+            ..default()
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn over(mut self, secs: f32) -> Self {
+        self.duration = secs;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// A screen transition animation for entering the current [`Screen`].
+    pub fn enter(self) -> impl Bundle {
+        (
+            widget::overlay(1000),
+            ScreenTransition::new(Some(self), None),
+        )
+    }
+
+    /// A screen transition animation for exiting the current [`Screen`].
+    ///
+    /// The next screen will be entered with the same style and easing.
+    #[allow(dead_code)]
+    pub fn exit_to(self, to_screen: Screen) -> impl Bundle {
+        (
+            widget::blocking_overlay(1000),
+            ScreenTransition::new(Some(self), Some(to_screen)),
+        )
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TransitionStyle {
+    /// Fade the whole screen at once.
+    Fade,
+    /// Sweep the cover across the screen.
+    Wipe(TransitionDir),
+    /// Close or open a circle around the center of the screen.
+    Iris,
+    /// Cover the screen one random cell at a time, in a grid of (columns, rows).
+    Dissolve(u16, u16),
+    /// Slide the cover in, pushing the screen's UI out of the way.
+    Slide(TransitionDir),
+}
+
+/// The direction that a [`TransitionStyle`] moves in.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TransitionDir {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl TransitionDir {
+    /// The unit vector in UI space, where +y is down.
+    fn to_vec2(self) -> Vec2 {
+        match self {
+            Self::Left => Vec2::NEG_X,
+            Self::Right => Vec2::X,
+            Self::Up => Vec2::NEG_Y,
+            Self::Down => Vec2::Y,
+        }
+    }
+}
+
+/// The root entity of an active screen transition.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ScreenTransition {
+    /// The transition to play, or `None` to use the default from the config.
+    transition: Option<Transition>,
+    /// The screen to enter when finished, or `None` for an enter transition.
+    to_screen: Option<Screen>,
+    timer: Timer,
+}
+
+impl Configure for ScreenTransition {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_observer(setup_screen_transition);
+        app.add_systems(StateFlush, Screen::ANY.on_enter(spawn_transition_in));
+        app.add_systems(
+            Update,
+            tick_screen_transitions.in_set(UpdateSystems::TickTimers),
+        );
+        app.add_systems(
+            PostUpdate,
+            apply_fade_transition.in_set(PostColorSystems::Blend),
+        );
+    }
+}
+
+impl ScreenTransition {
+    fn new(transition: Option<Transition>, to_screen: Option<Screen>) -> Self {
+        let secs = transition.as_ref().map_or(0.0, |x| x.duration);
+        Self {
+            transition,
+            to_screen,
+            timer: Timer::from_seconds(secs, TimerMode::Once),
+        }
+    }
+
+    /// Whether this is an exit transition.
+    pub fn is_exit(&self) -> bool {
+        self.to_screen.is_some()
+    }
+
+    /// The total duration of the transition, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.timer.duration().as_secs_f32()
+    }
+
+    /// How much of the screen is covered, from 0 to 1.
+    fn coverage(&self) -> f32 {
+        let Some(transition) = &self.transition else {
+            return 1.0;
+        };
+        let t = EasingCurve::new(0.0, 1.0, transition.ease).sample_clamped(self.timer.fraction());
+        if self.is_exit() { t } else { 1.0 - t }
+    }
+}
+
+fn setup_screen_transition(
+    trigger: Trigger<OnAdd, ScreenTransition>,
+    mut commands: Commands,
+    config: ConfigRef<TransitionConfig>,
+    mut transition_query: Query<&mut ScreenTransition>,
+) {
+    let entity = trigger.target();
+    let mut screen_transition = r!(transition_query.get_mut(entity));
+    if screen_transition.transition.is_none() {
+        let is_exit = screen_transition.is_exit();
+        let transition = config
+            .get()
+            .map(|x| if is_exit { &x.exit } else { &x.enter })
+            .cloned()
+            .unwrap_or_default();
+        *screen_transition = ScreenTransition::new(Some(transition), screen_transition.to_screen);
+    }
+    let transition = r!(screen_transition.transition.as_ref());
+
+    let mut entity = commands.entity(entity);
+    match transition.style {
+        TransitionStyle::Fade => {
+            entity.insert(ThemeColor::Body.set::<BackgroundColor>());
+        },
+        TransitionStyle::Wipe(_) | TransitionStyle::Slide(_) => {
+            entity.with_child((
+                Name::new("TransitionPanel"),
+                Node::DEFAULT.full_size().abs(),
+                ThemeColor::Body.set::<BackgroundColor>(),
+                Pickable::IGNORE,
+                TransitionPanel,
+            ));
+        },
+        TransitionStyle::Iris => {
+            entity.with_child((
+                Name::new("TransitionIris"),
+                Node {
+                    left: Percent(50.0),
+                    top: Percent(50.0),
+                    ..Node::DEFAULT.abs()
+                },
+                BorderRadius::MAX,
+                ThemeColor::Body.set::<BorderColor>(),
+                Pickable::IGNORE,
+                TransitionIris,
+            ));
+        },
+        TransitionStyle::Dissolve(columns, rows) => {
+            // Give each cell an evenly spaced threshold in a random order.
+            let n = columns as usize * rows as usize;
+            let mut thresholds = (1..=n).map(|i| i as f32 / n as f32).collect::<Vec<_>>();
+            thresholds.shuffle(&mut thread_rng());
+
+            entity.insert(Node {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::flex(columns, 1.0),
+                grid_template_rows: RepeatedGridTrack::flex(rows, 1.0),
+                ..Node::DEFAULT.full_size().abs()
+            });
+            entity.with_children(|parent| {
+                for threshold in thresholds {
+                    parent.spawn((
+                        Name::new("DissolveCell"),
+                        Node::DEFAULT,
+                        ThemeColor::Body.set::<BackgroundColor>(),
+                        Pickable::IGNORE,
+                        DissolveCell(threshold),
+                    ));
+                }
+            });
+        },
+    }
+}
+
+fn spawn_transition_in(
+    mut commands: Commands,
+    transition_query: Query<(), With<ScreenTransition>>,
+) {
+    // An exit transition may have already spawned the enter transition.
+    rq!(transition_query.is_empty());
+    commands.spawn(transition_in());
+}
+
+fn tick_screen_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut late: LateCommands,
+    config: ConfigRef<TransitionConfig>,
+    mut screen: NextMut<Screen>,
+    mut transition_query: Query<(Entity, &mut ScreenTransition)>,
+) {
+    for (entity, mut transition) in &mut transition_query {
+        if transition.timer.finished() {
+            if let Some(to_screen) = transition.to_screen {
+                screen.trigger().enter(to_screen);
+
+                // Enter the next screen with the same style and easing.
+                let mut enter = c!(transition.transition.clone());
+                enter.duration = config
+                    .get()
+                    .map_or(Transition::default().duration, |x| x.enter.duration);
+                commands.spawn(enter.enter());
+            }
+            late.commands().entity(entity).despawn();
+        }
+        transition.timer.tick(time.delta());
+    }
+}
+
+fn apply_fade_transition(mut transition_query: Query<(&ScreenTransition, &mut BackgroundColor)>) {
+    for (transition, mut color) in &mut transition_query {
+        cq!(matches!(
+            transition.transition.as_ref().map(|x| x.style),
+            Some(TransitionStyle::Fade),
+        ));
+        color.0.set_alpha(transition.coverage());
+    }
+}
+
+/// A panel that covers the screen during a [`TransitionStyle::Wipe`] or
+/// [`TransitionStyle::Slide`].
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[require(Backup<Transform>)]
+struct TransitionPanel;
+
+impl Configure for TransitionPanel {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            apply_transition_panel.in_set(PostTransformSystems::Blend),
+        );
+    }
+}
+
+fn apply_transition_panel(
+    transition_query: Query<&ScreenTransition>,
+    mut panel_query: Query<(&ChildOf, &ComputedNode, &mut Transform), With<TransitionPanel>>,
+    mut screen_root_query: Query<&mut Transform, (With<ScreenRootUi>, Without<TransitionPanel>)>,
+) {
+    for (child_of, computed_node, mut transform) in &mut panel_query {
+        let transition = c!(transition_query.get(child_of.parent()));
+        let (dir, push) = match c!(transition.transition.as_ref()).style {
+            TransitionStyle::Wipe(dir) => (dir, false),
+            TransitionStyle::Slide(dir) => (dir, true),
+            _ => continue,
+        };
+
+        // Move in from behind when exiting, and continue out ahead when entering.
+        // The screen's UI is pushed along right in front of or behind the panel.
+        let offset = dir.to_vec2() * computed_node.size();
+        let coverage = transition.coverage();
+        let (panel, screen) = if transition.to_screen.is_some() {
+            (coverage - 1.0, coverage)
+        } else {
+            (1.0 - coverage, -coverage)
+        };
+        transform.translation += (panel * offset).extend(0.0);
+
+        cq!(push);
+        for mut transform in &mut screen_root_query {
+            transform.translation += (screen * offset).extend(0.0);
+        }
+    }
+}
+
+/// The radius of the fully open iris, in [`Vmax`].
+///
+/// This reaches the corners of the screen for any aspect ratio.
+const IRIS_RADIUS: f32 = 71.0;
+
+/// A ring around a circular hole for a [`TransitionStyle::Iris`].
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct TransitionIris;
+
+impl Configure for TransitionIris {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(PostUpdate, apply_transition_iris.before(UiSystem::Layout));
+    }
+}
+
+fn apply_transition_iris(
+    transition_query: Query<&ScreenTransition>,
+    mut iris_query: Query<(&ChildOf, &mut Node), With<TransitionIris>>,
+) {
+    for (child_of, mut node) in &mut iris_query {
+        let transition = c!(transition_query.get(child_of.parent()));
+
+        // Keep the outer edge of the ring beyond the corners of the screen.
+        let radius = IRIS_RADIUS * (1.0 - transition.coverage());
+        let outer_radius = radius + IRIS_RADIUS;
+        node.width = Vmax(2.0 * outer_radius);
+        node.height = Vmax(2.0 * outer_radius);
+        node.margin = UiRect::new(Vmax(-outer_radius), Auto, Vmax(-outer_radius), Auto);
+        node.border = UiRect::all(Vmax(IRIS_RADIUS));
+    }
+}
+
+/// A cell in a [`TransitionStyle::Dissolve`] grid that's shown past a coverage threshold.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct DissolveCell(f32);
+
+impl Configure for DissolveCell {
+    fn configure(app: &mut App) {
+        app.register_type::<Self>();
+        app.add_systems(
+            PostUpdate,
+            apply_dissolve_cell.in_set(PostColorSystems::Blend),
+        );
+    }
+}
+
+fn apply_dissolve_cell(
+    transition_query: Query<&ScreenTransition>,
+    mut cell_query: Query<(&DissolveCell, &ChildOf, &mut BackgroundColor)>,
+) {
+    for (cell, child_of, mut color) in &mut cell_query {
+        let transition = c!(transition_query.get(child_of.parent()));
+        let alpha = if transition.coverage() >= cell.0 {
+            1.0
+        } else {
+            0.0
+        };
+        color.0.set_alpha(alpha);
+    }
+}
+
+/// The default screen transition animation for entering the current [`Screen`].
+pub fn transition_in() -> impl Bundle {
+    (widget::overlay(1000), ScreenTransition::new(None, None))
+}
+
+/// The default screen transition animation for exiting the current [`Screen`].
+pub fn transition_out(to_screen: Screen) -> impl Bundle {
+    (
+        widget::blocking_overlay(1000),
+        ScreenTransition::new(None, Some(to_screen)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage() {
+        for (ease, to_screen, elapsed, want) in [
+            // Exit transitions cover the screen, and enter transitions uncover it.
+            (EaseFunction::Linear, Some(Screen::Title), 0.5, 0.25),
+            (EaseFunction::Linear, None, 0.5, 0.75),
+            (EaseFunction::Linear, Some(Screen::Title), 3.0, 1.0),
+            (EaseFunction::Linear, None, 3.0, 0.0),
+            // Easing.
+            (EaseFunction::QuadraticIn, Some(Screen::Title), 1.0, 0.25),
+            (EaseFunction::QuadraticIn, None, 1.0, 0.75),
+        ] {
+            let mut transition = ScreenTransition::new(
+                Some(Transition::fade().over(2.0).with_ease(ease)),
+                to_screen,
+            );
+            transition.timer.tick(Duration::from_secs_f32(elapsed));
+            assert_eq!(
+                transition.coverage(),
+                want,
+                "{ease:?} to {to_screen:?} after {elapsed}s",
+            );
+        }
+        assert_eq!(ScreenTransition::new(None, None).coverage(), 1.0);
+    }
+}
@@ -18,23 +18,27 @@ pub(super) fn plugin(app: &mut App) {
     )>();
 }
 
-#[derive(Asset, Reflect, Serialize, Deserialize)]
+/// UI theme settings.
+#[derive(Asset, Reflect, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct ThemeConfig {
+    /// The color palette, in the order of the `ThemeColor` variants.
     pub colors: ThemeColorList,
 }
 
 impl Config for ThemeConfig {
     const FILE: &'static str = "theme.ron";
 
-    fn on_load(&self, world: &mut World) {
-        r!(world.get_resource_mut::<ClearColor>()).0 = self.colors[ThemeColor::Body];
+    fn on_load(&self, world: &mut World, changed: &ConfigChanged<Self>) {
+        if changed.is_changed(&format!("colors[{}]", ThemeColor::Body as usize)) {
+            r!(world.get_resource_mut::<ClearColor>()).0 = self.colors[ThemeColor::Body];
+        }
     }
 }
 
 // Note: The length of this array MUST equal the number of `ThemeColor` variants.
-#[derive(Reflect, Serialize, Deserialize)]
-pub struct ThemeColorList([Color; 12]);
+#[derive(Reflect, Serialize, Deserialize, Clone)]
+pub struct ThemeColorList([Color; 13]);
 
 impl Index<ThemeColor> for ThemeColorList {
     type Output = Color;
@@ -45,7 +49,7 @@ impl Index<ThemeColor> for ThemeColorList {
 }
 
 /// See: <https://getbootstrap.com/docs/5.3/customize/color/>.
-#[derive(Reflect, Clone, Copy, Default)]
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Default)]
 pub enum ThemeColor {
     // Absolute colors.
     #[default]
@@ -66,6 +70,7 @@ pub enum ThemeColor {
     Popup,
     PopupBorder,
     Overlay,
+    Letterbox,
 }
 
 impl ThemeColor {
@@ -3,10 +3,8 @@ use bevy::reflect::GetTypeRegistration;
 use bevy::reflect::Typed;
 
 use crate::animation::offset::NodeOffset;
-use crate::core::audio::AudioSettings;
-use crate::core::audio::ui_audio;
+use crate::core::audio::sound::Sound;
 use crate::prelude::*;
-use crate::theme::ThemeAssets;
 
 pub(super) fn plugin(app: &mut App) {
     app.configure::<(
@@ -165,8 +163,6 @@ impl Configure for InteractionSfx {
 
 fn play_hover_sfx(
     trigger: Trigger<Pointer<Over>>,
-    audio_settings: Res<AudioSettings>,
-    assets: Res<ThemeAssets>,
     sfx_query: Query<Option<&InteractionDisabled>, With<InteractionSfx>>,
     mut commands: Commands,
 ) {
@@ -174,13 +170,11 @@ fn play_hover_sfx(
     let disabled = rq!(sfx_query.get(target));
     rq!(!matches!(disabled, Some(InteractionDisabled(true))));
 
-    commands.spawn(ui_audio(&audio_settings, assets.sfx_hover.clone()));
+    commands.spawn(Sound::new("ui_hover"));
 }
 
 fn play_click_sfx(
     trigger: Trigger<Pointer<Click>>,
-    audio_settings: Res<AudioSettings>,
-    assets: Res<ThemeAssets>,
     sfx_query: Query<Option<&InteractionDisabled>, With<InteractionSfx>>,
     mut commands: Commands,
 ) {
@@ -188,5 +182,5 @@ fn play_click_sfx(
     let disabled = rq!(sfx_query.get(target));
     rq!(!matches!(disabled, Some(InteractionDisabled(true))));
 
-    commands.spawn(ui_audio(&audio_settings, assets.sfx_click.clone()));
+    commands.spawn(Sound::new("ui_click"));
 }
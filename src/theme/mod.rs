@@ -29,8 +29,6 @@ pub mod prelude {
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.configure::<ThemeAssets>();
-
     app.add_plugins((
         color::plugin,
         grid::plugin,
@@ -39,19 +37,3 @@ pub(super) fn plugin(app: &mut App) {
         tooltip::plugin,
     ));
 }
-
-#[derive(AssetCollection, Resource, Reflect, Default)]
-#[reflect(Resource)]
-pub struct ThemeAssets {
-    #[asset(path = "audio/sfx/251390__deadsillyrabbit__button_hover-mp3.ogg")]
-    pub sfx_hover: Handle<AudioSource>,
-    #[asset(path = "audio/sfx/253168__suntemple__sfx-ui-button-click.ogg")]
-    pub sfx_click: Handle<AudioSource>,
-}
-
-impl Configure for ThemeAssets {
-    fn configure(app: &mut App) {
-        app.register_type::<Self>();
-        app.init_collection::<Self>();
-    }
-}
@@ -1,18 +1,50 @@
 use std::any::type_name;
+use std::fmt::Write as _;
 
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::asset::AssetLoader;
+use bevy::asset::LoadContext;
+use bevy::asset::io::Reader;
+#[cfg(feature = "native_dev")]
+use bevy::asset::io::file::FileAssetReader;
 use bevy::diagnostic::FrameCount;
 use bevy::ecs::event::EventCursor;
 use bevy::ecs::system::SystemParam;
-use bevy_common_assets::ron::RonAssetPlugin;
+use bevy::reflect::FromType;
+use bevy::reflect::GetTypeRegistration;
+use bevy::reflect::ReflectRef;
+use ron::value::RawValue;
+use serde::Deserializer;
+use serde::de::DeserializeOwned;
+use serde::de::MapAccess;
+use serde::de::Visitor;
 
 use crate::prelude::*;
 
-pub trait Config: Asset + Serialize + for<'de> Deserialize<'de> {
+pub trait Config:
+    Asset + Reflect + GetTypeRegistration + Clone + Serialize + for<'de> Deserialize<'de>
+{
     const FILE: &'static str;
     const FOLDER: &'static str = "config";
+    const FORMAT: ConfigFormat = ConfigFormat::Ron;
 
-    fn on_load(&self, world: &mut World) {
-        let _ = world;
+    /// Check the config for invalid values while it's loaded.
+    ///
+    /// If this fails, the load fails and the last valid value (if any) will be kept instead.
+    fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        Ok(())
+    }
+
+    /// Apply the config after it's loaded or modified.
+    ///
+    /// Use `changed` to skip reapplying fields that didn't change.
+    fn on_load(&self, world: &mut World, changed: &ConfigChanged<Self>) {
+        let _ = (world, changed);
+    }
+
+    /// Load the config's [`ConfigAsset`] fields as dependencies of the config.
+    fn load_assets(&mut self, load_context: &mut LoadContext) {
+        let _ = load_context;
     }
 
     fn count_progress(&self, asset_server: &AssetServer) -> Progress {
@@ -20,7 +52,16 @@ pub trait Config: Asset + Serialize + for<'de> Deserialize<'de> {
         true.into()
     }
 
-    fn progress(config: ConfigRef<Self>, asset_server: Res<AssetServer>) -> Progress {
+    /// Track progress towards loading the config and its assets.
+    fn progress(
+        config_handle: Res<ConfigHandle<Self>>,
+        config: ConfigRef<Self>,
+        asset_server: Res<AssetServer>,
+    ) -> Progress {
+        if !asset_server.is_loaded_with_dependencies(&config_handle.0) {
+            return false.into();
+        }
+
         config
             .get()
             .map(|x| x.count_progress(&asset_server))
@@ -28,6 +69,121 @@ pub trait Config: Asset + Serialize + for<'de> Deserialize<'de> {
     }
 }
 
+/// The file format of a [`Config`].
+///
+/// TOML and JSON require the `toml` and `json` cargo features.
+#[derive(Reflect, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ConfigFormat {
+    Ron,
+    #[cfg(feature = "toml")]
+    Toml,
+    #[cfg(feature = "json")]
+    Json,
+}
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+impl ConfigFormat {
+    /// The file extension that's assumed if a [`Config::FILE`] has none.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ron => "ron",
+            #[cfg(feature = "toml")]
+            Self::Toml => "toml",
+            #[cfg(feature = "json")]
+            Self::Json => "json",
+        }
+    }
+
+    pub fn deserialize<T: DeserializeOwned>(self, text: &str) -> Result<T, BoxedError> {
+        Ok(match self {
+            Self::Ron => ron::from_str(text)?,
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::from_str(text)?,
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::from_str(text)?,
+        })
+    }
+
+    /// Serialize a value into a pretty string.
+    pub fn serialize<T: Serialize>(self, value: &T) -> Result<String, BoxedError> {
+        Ok(match self {
+            Self::Ron => ron::ser::to_string_pretty(value, default())?,
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::to_string_pretty(value)?,
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::to_string_pretty(value)?,
+        })
+    }
+
+    /// Deep-merge an override layer into a base file.
+    fn merge(self, base: &str, layer: &str) -> Result<String, BoxedError> {
+        Ok(match self {
+            Self::Ron => merge_ron(base, layer),
+            #[cfg(feature = "toml")]
+            Self::Toml => {
+                let mut value = toml::from_str(base)?;
+                merge_json(&mut value, toml::from_str(layer)?);
+                toml::to_string(&value)?
+            },
+            #[cfg(feature = "json")]
+            Self::Json => {
+                let mut value = serde_json::from_str(base)?;
+                merge_json(&mut value, serde_json::from_str(layer)?);
+                serde_json::to_string(&value)?
+            },
+        })
+    }
+}
+
+/// An invalid value in a [`Config`].
+#[derive(Debug, Clone)]
+pub struct ConfigError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ConfigError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Type data for reflecting a [`Config`] type.
+#[derive(Clone)]
+pub struct ReflectConfig {
+    pub folder: &'static str,
+    pub file: &'static str,
+    pub format: ConfigFormat,
+    /// Deserialize the config from its format.
+    pub deserialize: fn(&str) -> Result<Box<dyn Reflect>, BoxedError>,
+    /// Serialize the config to its format, or `None` if the value is not this config type.
+    pub serialize: fn(&dyn Reflect) -> Option<String>,
+}
+
+impl<C: Config> FromType<C> for ReflectConfig {
+    fn from_type() -> Self {
+        Self {
+            folder: C::FOLDER,
+            file: C::FILE,
+            format: C::FORMAT,
+            deserialize: |text| Ok(Box::new(C::FORMAT.deserialize::<C>(text)?)),
+            serialize: |value| C::FORMAT.serialize(value.downcast_ref::<C>()?).ok(),
+        }
+    }
+}
+
 #[derive(Resource, Reflect)]
 #[reflect(Resource)]
 pub struct ConfigHandle<C: Config>(pub Handle<C>);
@@ -35,11 +191,20 @@ pub struct ConfigHandle<C: Config>(pub Handle<C>);
 impl<C: Config> Configure for ConfigHandle<C> {
     fn configure(app: &mut App) {
         app.register_type::<Self>();
-        app.add_plugins(RonAssetPlugin::<C>::new(&[C::FILE]));
+        app.register_type::<C>();
+        app.register_type_data::<C, ReflectConfig>();
+        app.init_asset::<C>();
+        app.register_asset_loader(ConfigLoader::<C>(PhantomData));
+        app.add_event::<ConfigChanged<C>>();
         app.add_systems(Startup, load_config::<C>);
+        #[cfg(feature = "native_dev")]
+        app.add_observer(save_config::<C>);
         app.add_systems(
             PreUpdate,
-            apply_config::<C>.run_if(on_event::<AssetEvent<C>>),
+            (
+                report_config_load_failure::<C>.run_if(on_event::<AssetLoadFailedEvent<C>>),
+                apply_config::<C>.run_if(on_event::<AssetEvent<C>>),
+            ),
         );
     }
 }
@@ -50,7 +215,176 @@ fn load_config<C: Config>(world: &mut World) {
     world.insert_resource(ConfigHandle::<C>(handle));
 }
 
-fn apply_config<C: Config>(world: &mut World, mut cursor: Local<EventCursor<AssetEvent<C>>>) {
+/// The override layers of a [`Config`], from lowest to highest priority.
+///
+/// For example, `camera.ron` can be overridden by `camera.native.ron` in native builds,
+/// and then by `camera.local.ron`, which is untracked by git.
+const LAYERS: &[&str] = &[
+    #[cfg(feature = "native")]
+    "native",
+    #[cfg(feature = "web")]
+    "web",
+    "local",
+];
+
+/// The asset paths of the override [`LAYERS`] of a [`Config`], from lowest to highest priority.
+fn layer_paths<C: Config>() -> impl Iterator<Item = String> {
+    let (stem, extension) = C::FILE
+        .rsplit_once('.')
+        .unwrap_or((C::FILE, C::FORMAT.extension()));
+    LAYERS
+        .iter()
+        .map(move |layer| format!("{}/{stem}.{layer}.{extension}", C::FOLDER))
+}
+
+/// Loads a [`Config`] by deep-merging its override [`LAYERS`] into its base file.
+///
+/// NOTE: Hot-reloading only tracks the layers that existed when the config was last loaded.
+struct ConfigLoader<C: Config>(PhantomData<C>);
+
+impl<C: Config> AssetLoader for ConfigLoader<C> {
+    type Asset = C;
+    type Settings = ();
+    type Error = BoxedError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let mut config = String::from_utf8(bytes)?;
+
+        for path in layer_paths::<C>() {
+            let Ok(bytes) = load_context.read_asset_bytes(path.as_str()).await else {
+                continue;
+            };
+            config = C::FORMAT.merge(&config, &String::from_utf8(bytes)?)?;
+        }
+
+        let mut config = C::FORMAT.deserialize::<C>(&config)?;
+        if let Err(errors) = config.validate() {
+            let mut message = String::from("Invalid config");
+            for error in errors {
+                let _ = write!(message, "\n    {error}");
+            }
+            return Err(message.into());
+        }
+        config.load_assets(load_context);
+        Ok(config)
+    }
+}
+
+/// Deep-merges two RON values, field by field.
+///
+/// Structs are merged recursively. Any other value in `layer` replaces the value in `base`.
+fn merge_ron(base: &str, layer: &str) -> String {
+    let (Some(mut base_fields), Some(layer_fields)) = (parse_fields(base), parse_fields(layer))
+    else {
+        return layer.to_string();
+    };
+
+    for (name, value) in layer_fields {
+        if let Some((_, base_value)) = base_fields.iter_mut().find(|(x, _)| *x == name) {
+            let merged = merge_ron(base_value.get_ron(), value.get_ron());
+            *base_value = c!(RawValue::from_boxed_ron(merged.into_boxed_str()));
+        } else {
+            base_fields.push((name, value));
+        }
+    }
+
+    // Put each separator on its own line, in case a value ends with a line comment.
+    let mut merged = String::from("(");
+    for (name, value) in &base_fields {
+        let _ = write!(merged, "{name}: {}\n, ", value.get_ron().trim());
+    }
+    merged.push(')');
+    merged
+}
+
+/// Parses a RON struct into its fields, leaving the field values unparsed.
+fn parse_fields(ron: &str) -> Option<Vec<(String, Box<RawValue>)>> {
+    // Maps are not merged, since their keys are not field names.
+    if ron.trim_start().starts_with('{') {
+        return None;
+    }
+
+    ron::from_str::<RonFields>(ron).ok().map(|x| x.0)
+}
+
+struct RonFields(Vec<(String, Box<RawValue>)>);
+
+impl<'de> Deserialize<'de> for RonFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(RonFieldsVisitor)
+    }
+}
+
+struct RonFieldsVisitor;
+
+impl<'de> Visitor<'de> for RonFieldsVisitor {
+    type Value = RonFields;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a struct")
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(RonFields(vec![]))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = vec![];
+        while let Some(field) = map.next_entry()? {
+            fields.push(field);
+        }
+        Ok(RonFields(fields))
+    }
+}
+
+/// Deep-merges two JSON values (also used for TOML), key by key.
+///
+/// Objects are merged recursively. Any other value in `layer` replaces the value in `base`.
+#[cfg(any(feature = "toml", feature = "json"))]
+fn merge_json(base: &mut serde_json::Value, layer: serde_json::Value) {
+    match (base, layer) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_json(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    },
+                }
+            }
+        },
+        (base, layer) => *base = layer,
+    }
+}
+
+fn report_config_load_failure<C: Config>(
+    mut failed_events: EventReader<AssetLoadFailedEvent<C>>,
+    config_handle: Res<ConfigHandle<C>>,
+    frame: Res<FrameCount>,
+) {
+    for event in failed_events.read() {
+        cq!(event.id == config_handle.0.id());
+        error!(
+            "[Frame {}] Failed to load config: {}, keeping last valid value\n{}",
+            frame.0,
+            type_name::<C>(),
+            event.error,
+        );
+    }
+}
+
+fn apply_config<C: Config>(
+    world: &mut World,
+    mut cursor: Local<EventCursor<AssetEvent<C>>>,
+    mut last_applied: Local<Option<C>>,
+) {
     if !cursor
         .read(r!(world.get_resource::<Events<AssetEvent<_>>>()))
         .any(|event| {
@@ -61,18 +395,202 @@ fn apply_config<C: Config>(world: &mut World, mut cursor: Local<EventCursor<Asse
         return;
     }
 
-    info!(
-        "[Frame {}] Applying config: {}",
-        r!(world.get_resource::<FrameCount>()).0,
-        type_name::<C>(),
-    );
+    let frame = r!(world.get_resource::<FrameCount>()).0;
     world.resource_scope(|world, config: Mut<Assets<C>>| {
-        let config_handle = r!(world.get_resource::<ConfigHandle<C>>());
-        let config = r!(config.get(&config_handle.0));
-        config.on_load(world);
+        let config_handle = r!(world.get_resource::<ConfigHandle<C>>()).0.clone();
+        let value = r!(config.get(&config_handle));
+
+        // Skip reapplying an unchanged value (e.g. after saving the config).
+        let changes = last_applied.as_ref().map(|old| {
+            let mut changes = vec![];
+            diff_fields(
+                "",
+                old.as_partial_reflect(),
+                value.as_partial_reflect(),
+                &mut changes,
+            );
+            changes
+        });
+        if changes.as_ref().is_some_and(|x| x.is_empty()) {
+            return;
+        }
+
+        info!("[Frame {frame}] Applying config: {}", type_name::<C>());
+        for (path, old, new) in changes.iter().flatten() {
+            info!("    {path}: {old} -> {new}");
+        }
+
+        *last_applied = Some(value.clone());
+        let changed = ConfigChanged::<C> {
+            paths: changes.map(|x| x.into_iter().map(|(path, ..)| path).collect()),
+            _phantom: PhantomData,
+        };
+        value.on_load(world, &changed);
+        world.send_event(changed);
     });
 }
 
+/// Collect the path, old value, and new value of each field that differs between two values.
+///
+/// Single-field tuple structs and tuple variants (like `Some`) are transparent in the path.
+fn diff_fields(
+    path: &str,
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    changes: &mut Vec<(String, String, String)>,
+) {
+    let field_path = |name: &str| {
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{path}.{name}")
+        }
+    };
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) => {
+            for (i, new_field) in new.iter_fields().enumerate() {
+                let name = c!(new.name_at(i));
+                diff_fields(&field_path(name), c!(old.field(name)), new_field, changes);
+            }
+        },
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            let is_newtype = new.field_len() == 1;
+            for (i, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                let path = if is_newtype {
+                    path.to_string()
+                } else {
+                    field_path(&i.to_string())
+                };
+                diff_fields(&path, old, new, changes);
+            }
+        },
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            for (i, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                diff_fields(&field_path(&i.to_string()), old, new, changes);
+            }
+        },
+        (ReflectRef::List(old), ReflectRef::List(new)) if old.len() == new.len() => {
+            for (i, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                diff_fields(&format!("{path}[{i}]"), old, new, changes);
+            }
+        },
+        (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => {
+            for (i, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                diff_fields(&format!("{path}[{i}]"), old, new, changes);
+            }
+        },
+        (ReflectRef::Enum(old), ReflectRef::Enum(new))
+            if old.variant_name() == new.variant_name() && old.field_len() == new.field_len() =>
+        {
+            let is_newtype = new.field_len() == 1 && new.name_at(0).is_none();
+            for i in 0..new.field_len() {
+                let path = match new.name_at(i) {
+                    Some(name) => field_path(name),
+                    None if is_newtype => path.to_string(),
+                    None => field_path(&i.to_string()),
+                };
+                diff_fields(&path, c!(old.field_at(i)), c!(new.field_at(i)), changes);
+            }
+        },
+        _ => {
+            let old_debug = format!("{old:?}");
+            let new_debug = format!("{new:?}");
+            if !old
+                .reflect_partial_eq(new)
+                .unwrap_or_else(|| old_debug == new_debug)
+            {
+                changes.push((path.to_string(), old_debug, new_debug));
+            }
+        },
+    }
+}
+
+/// An event that's sent when a [`Config`] is applied.
+#[derive(Event, Debug)]
+pub struct ConfigChanged<C: Config> {
+    /// The paths of the fields that changed (e.g. `"colors[2]"`),
+    /// or `None` if this is the first time the config was applied.
+    pub paths: Option<Vec<String>>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: Config> ConfigChanged<C> {
+    /// Check if the field at `path` or any of its subfields changed.
+    pub fn is_changed(&self, path: &str) -> bool {
+        self.paths.as_ref().is_none_or(|paths| {
+            paths.iter().any(|x| {
+                x.strip_prefix(path)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+            })
+        })
+    }
+}
+
+/// An event that saves every loaded [`Config`] to its base file.
+///
+/// A config with an active override layer is not saved, since that would bake the layer
+/// into the base file.
+///
+/// NOTE: This will not preserve comments in the base file.
+#[cfg(feature = "native_dev")]
+#[derive(Event, Debug)]
+pub struct SaveConfigs;
+
+#[cfg(feature = "native_dev")]
+fn save_config<C: Config>(_: Trigger<SaveConfigs>, config: ConfigRef<C>, frame: Res<FrameCount>) {
+    let config = rq!(config.get());
+    let root = FileAssetReader::new("assets").root_path().clone();
+    let layers = layer_paths::<C>()
+        .filter(|x| root.join(x).exists())
+        .collect::<Vec<_>>();
+    if !layers.is_empty() {
+        warn!(
+            "[Frame {}] Skipped saving config: {}, override layers are active: {}",
+            frame.0,
+            type_name::<C>(),
+            layers.join(", "),
+        );
+        return;
+    }
+
+    let path = root.join(C::FOLDER).join(C::FILE);
+    let text = r!(C::FORMAT.serialize(config));
+    r!(std::fs::write(&path, text));
+    info!("[Frame {}] Saved config: {}", frame.0, path.display());
+}
+
+/// A reference to an asset in a [`Config`], (de)serialized as its asset path.
+///
+/// Call [`ConfigAsset::load`] from [`Config::load_assets`] to load the asset.
+#[derive(Reflect, Serialize, Deserialize)]
+#[serde(transparent, bound = "")]
+pub struct ConfigAsset<A: Asset>(
+    pub String,
+    #[serde(skip)]
+    #[reflect(ignore)]
+    Handle<A>,
+);
+
+impl<A: Asset> Clone for ConfigAsset<A> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<A: Asset> ConfigAsset<A> {
+    /// Load the asset as a dependency of its config.
+    pub fn load(&mut self, load_context: &mut LoadContext) {
+        self.1 = load_context.load(self.0.clone());
+    }
+
+    pub fn handle(&self) -> &Handle<A> {
+        &self.1
+    }
+}
+
 #[derive(SystemParam)]
 pub struct ConfigRef<'w, C: Config> {
     handle: Option<Res<'w, ConfigHandle<C>>>,
@@ -96,3 +614,52 @@ impl<C: Config> ConfigMut<'_, C> {
         self.handle.as_ref().and_then(|x| self.assets.get_mut(&x.0))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ron() {
+        for (base, layer, want) in [
+            ("(a: 1, b: 2)", "()", "(a: 1\n, b: 2\n, )"),
+            ("(a: 1, b: 2)", "(b: 3)", "(a: 1\n, b: 3\n, )"),
+            ("(a: 1)", "(b: Some(2))", "(a: 1\n, b: Some(2)\n, )"),
+            (
+                "(a: (x: 1, y: 2))",
+                "(a: (y: 3))",
+                "(a: (x: 1\n, y: 3\n, )\n, )",
+            ),
+            ("(a: Foo(1))", "(a: Bar)", "(a: Bar\n, )"),
+            ("(a: [1, 2])", "(a: [3])", "(a: [3]\n, )"),
+            ("(a: {\"x\": 1})", "(a: {\"y\": 2})", "(a: {\"y\": 2}\n, )"),
+            (
+                "(a: 1)\n",
+                "// Comment.\n(a: 2 /* Comment. */)\n",
+                "(a: 2 /* Comment. */\n, )",
+            ),
+            (
+                "(a: 2 // Comment.\n)",
+                "(b: None)",
+                "(a: 2 // Comment.\n, b: None\n, )",
+            ),
+        ] {
+            let merged = merge_ron(base, layer);
+            assert_eq!(merged, want);
+            assert!(ron::from_str::<ron::Value>(&merged).is_ok(), "{merged}");
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_merge_toml() {
+        for (base, layer, want) in [
+            ("a = 1\nb = 2\n", "", "a = 1\nb = 2\n"),
+            ("a = 1\nb = 2\n", "b = 3\n", "a = 1\nb = 3\n"),
+            ("[a]\nx = 1\ny = 2\n", "[a]\ny = 3\n", "[a]\nx = 1\ny = 3\n"),
+            ("a = [1, 2]\n", "a = [3]\n", "a = [3]\n"),
+        ] {
+            assert_eq!(ConfigFormat::Toml.merge(base, layer).unwrap(), want);
+        }
+    }
+}
@@ -12,6 +12,10 @@ pub mod selection;
 #[allow(unused_imports)]
 pub mod prelude {
     pub use super::config::Config;
+    pub use super::config::ConfigAsset;
+    pub use super::config::ConfigChanged;
+    pub use super::config::ConfigError;
+    pub use super::config::ConfigFormat;
     pub use super::config::ConfigHandle;
     pub use super::config::ConfigMut;
     pub use super::config::ConfigRef;